@@ -1,13 +1,17 @@
 use anyhow::anyhow;
 use ethabi::{
-	decode, encode,
+	decode, decode_lenient, encode,
 	param_type::{ParamType, Reader},
-	token::{LenientTokenizer, StrictTokenizer, Token, Tokenizer},
-	Contract, Event, Function, Hash,
+	split_selector,
+	token::{LenientTokenizer, NamedParamType, NamedToken, StrictTokenizer, Token, Tokenizer},
+	Contract, Event, Function, Hash, Param,
 };
 use itertools::Itertools;
 use sha3::{Digest, Keccak256};
-use std::fs::File;
+use std::{
+	fs::{self, File},
+	io::{self, Read},
+};
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -31,6 +35,15 @@ enum Encode {
 		#[structopt(short, long)]
 		lenient: bool,
 	},
+	/// Specify the function inline by its signature, such as `transfer(address,uint256)`.
+	Signature {
+		signature: String,
+		#[structopt(short, number_of_values = 1)]
+		params: Vec<String>,
+		/// Allow short representation of input params.
+		#[structopt(short, long)]
+		lenient: bool,
+	},
 	/// Specify types of input params inline.
 	Params {
 		/// Pairs of types directly followed by params in the form:
@@ -47,12 +60,53 @@ enum Encode {
 #[derive(StructOpt, Debug)]
 enum Decode {
 	/// Load function from JSON ABI file.
-	Function { abi_path: String, function_name_or_signature: String, data: String },
+	Function {
+		abi_path: String,
+		function_name_or_signature: String,
+		/// Hex encoded data, `@path` to read it from a file or `-` to read it from stdin.
+		data: String,
+		/// Print decoded values as a JSON array.
+		#[structopt(long)]
+		json: bool,
+		/// Print addresses with EIP-55 checksums.
+		#[structopt(long)]
+		checksum: bool,
+		/// Right pad data whose last word is cut short with zeros before decoding.
+		#[structopt(short, long)]
+		lenient: bool,
+	},
 	/// Specify types of input params inline.
 	Params {
 		#[structopt(short, name = "type", number_of_values = 1)]
 		types: Vec<String>,
+		/// Hex encoded data, `@path` to read it from a file or `-` to read it from stdin.
 		data: String,
+		/// Print decoded values as a JSON array.
+		#[structopt(long)]
+		json: bool,
+		/// Print addresses with EIP-55 checksums.
+		#[structopt(long)]
+		checksum: bool,
+		/// Right pad data whose last word is cut short with zeros before decoding.
+		#[structopt(short, long)]
+		lenient: bool,
+	},
+	/// Decode function call data, looking up its selector in a signature dictionary.
+	Calldata {
+		/// File of `selector,signature` lines, such as `a9059cbb,transfer(address,uint256)`.
+		#[structopt(long)]
+		signatures: String,
+		/// Hex encoded data, `@path` to read it from a file or `-` to read it from stdin.
+		data: String,
+		/// Print decoded values as a JSON array.
+		#[structopt(long)]
+		json: bool,
+		/// Print addresses with EIP-55 checksums.
+		#[structopt(long)]
+		checksum: bool,
+		/// Right pad data whose last word is cut short with zeros before decoding.
+		#[structopt(short, long)]
+		lenient: bool,
 	},
 	/// Decode event log.
 	Log {
@@ -60,7 +114,14 @@ enum Decode {
 		event_name_or_signature: String,
 		#[structopt(short = "l", name = "topic", number_of_values = 1)]
 		topics: Vec<String>,
+		/// Hex encoded data, `@path` to read it from a file or `-` to read it from stdin.
 		data: String,
+		/// Print decoded values as a JSON array.
+		#[structopt(long)]
+		json: bool,
+		/// Print addresses with EIP-55 checksums.
+		#[structopt(long)]
+		checksum: bool,
 	},
 }
 
@@ -81,13 +142,21 @@ where
 		Opt::Encode(Encode::Function { abi_path, function_name_or_signature, params, lenient }) => {
 			encode_input(&abi_path, &function_name_or_signature, &params, lenient)
 		}
+		Opt::Encode(Encode::Signature { signature, params, lenient }) => {
+			encode_signature_input(&signature, &params, lenient)
+		}
 		Opt::Encode(Encode::Params { params, lenient }) => encode_params(&params, lenient),
-		Opt::Decode(Decode::Function { abi_path, function_name_or_signature, data }) => {
-			decode_call_output(&abi_path, &function_name_or_signature, &data)
+		Opt::Decode(Decode::Function { abi_path, function_name_or_signature, data, json, checksum, lenient }) => {
+			decode_call_output(&abi_path, &function_name_or_signature, &data, json, checksum, lenient)
 		}
-		Opt::Decode(Decode::Params { types, data }) => decode_params(&types, &data),
-		Opt::Decode(Decode::Log { abi_path, event_name_or_signature, topics, data }) => {
-			decode_log(&abi_path, &event_name_or_signature, &topics, &data)
+		Opt::Decode(Decode::Params { types, data, json, checksum, lenient }) => {
+			decode_params(&types, &data, json, checksum, lenient)
+		}
+		Opt::Decode(Decode::Calldata { signatures, data, json, checksum, lenient }) => {
+			decode_calldata(&signatures, &data, json, checksum, lenient)
+		}
+		Opt::Decode(Decode::Log { abi_path, event_name_or_signature, topics, data, json, checksum }) => {
+			decode_log(&abi_path, &event_name_or_signature, &topics, &data, json, checksum)
 		}
 	}
 }
@@ -171,7 +240,28 @@ fn parse_tokens(params: &[(ParamType, &str)], lenient: bool) -> anyhow::Result<V
 
 fn encode_input(path: &str, name_or_signature: &str, values: &[String], lenient: bool) -> anyhow::Result<String> {
 	let function = load_function(path, name_or_signature)?;
+	encode_function_input(&function, values, lenient)
+}
+
+fn encode_signature_input(signature: &str, values: &[String], lenient: bool) -> anyhow::Result<String> {
+	let function = function_from_signature(signature)?;
+	encode_function_input(&function, values, lenient)
+}
+
+/// Builds a function with unnamed params from a signature such as `transfer(address,uint256)`.
+fn function_from_signature(signature: &str) -> anyhow::Result<Function> {
+	let (name, inputs, outputs) = Reader::read_function_signature(signature)?;
+	#[allow(deprecated)]
+	Ok(Function {
+		name,
+		inputs: inputs.into_iter().map(|kind| Param::new("", kind)).collect(),
+		outputs: outputs.into_iter().map(|kind| Param::new("", kind)).collect(),
+		constant: None,
+		state_mutability: Default::default(),
+	})
+}
 
+fn encode_function_input(function: &Function, values: &[String], lenient: bool) -> anyhow::Result<String> {
 	let params: Vec<_> =
 		function.inputs.iter().map(|param| param.kind.clone()).zip(values.iter().map(|v| v as &str)).collect();
 
@@ -196,51 +286,175 @@ fn encode_params(params: &[String], lenient: bool) -> anyhow::Result<String> {
 	Ok(hex::encode(result))
 }
 
-fn decode_call_output(path: &str, name_or_signature: &str, data: &str) -> anyhow::Result<String> {
+/// Reads the `data` argument of the decode subcommands.
+///
+/// `-` reads from stdin and `@path` reads from a file, anything else is decoded as hex. The
+/// contents of stdin or a file are decoded as hex if possible and used as raw bytes otherwise.
+fn read_data(data: &str) -> anyhow::Result<Vec<u8>> {
+	let contents = match (data, data.strip_prefix('@')) {
+		("-", _) => {
+			let mut contents = vec![];
+			io::stdin().read_to_end(&mut contents)?;
+			contents
+		}
+		(_, Some(path)) => fs::read(path)?,
+		_ => return Ok(hex::decode(data)?),
+	};
+
+	let hex = std::str::from_utf8(&contents).ok().map(|s| s.trim()).map(|s| s.strip_prefix("0x").unwrap_or(s));
+	match hex.map(hex::decode) {
+		Some(Ok(bytes)) => Ok(bytes),
+		_ => Ok(contents),
+	}
+}
+
+/// Decodes `data` with [`decode_lenient`] if `lenient` is set, which right pads
+/// data whose last word is cut short with zeros, and with [`decode`] otherwise.
+fn decode_data(types: &[ParamType], data: &[u8], lenient: bool) -> ethabi::Result<Vec<Token>> {
+	match lenient {
+		true => decode_lenient(types, data),
+		false => decode(types, data),
+	}
+}
+
+fn decode_call_output(
+	path: &str,
+	name_or_signature: &str,
+	data: &str,
+	json: bool,
+	checksum: bool,
+	lenient: bool,
+) -> anyhow::Result<String> {
 	let function = load_function(path, name_or_signature)?;
-	let data: Vec<u8> = hex::decode(data)?;
-	let tokens = function.decode_output(&data)?;
+	let data: Vec<u8> = read_data(data)?;
+	let tokens = decode_data(&function.output_param_types(), &data, lenient)?;
 	let types = function.outputs;
 
 	assert_eq!(types.len(), tokens.len());
 
-	let result =
-		types.iter().zip(tokens.iter()).map(|(ty, to)| format!("{} {to}", ty.kind)).collect::<Vec<String>>().join("\n");
+	if json {
+		return decoded_to_json(types.iter().map(NamedParamType::from).zip(tokens), checksum);
+	}
+
+	let result = types
+		.iter()
+		.zip(tokens.iter())
+		.map(|(ty, to)| format!("{} {}", ty.kind, to.display(checksum)))
+		.collect::<Vec<String>>()
+		.join("\n");
 
 	Ok(result)
 }
 
-fn decode_params(types: &[String], data: &str) -> anyhow::Result<String> {
+fn decode_params(types: &[String], data: &str, json: bool, checksum: bool, lenient: bool) -> anyhow::Result<String> {
 	let types: Vec<ParamType> = types.iter().map(|s| Reader::read(s)).collect::<Result<_, _>>()?;
 
-	let data: Vec<u8> = hex::decode(data)?;
+	let data: Vec<u8> = read_data(data)?;
 
-	let tokens = decode(&types, &data)?;
+	let tokens = decode_data(&types, &data, lenient)?;
 
 	assert_eq!(types.len(), tokens.len());
 
-	let result =
-		types.iter().zip(tokens.iter()).map(|(ty, to)| format!("{ty} {to}")).collect::<Vec<String>>().join("\n");
+	if json {
+		return decoded_to_json(types.iter().map(|ty| NamedParamType::new("", ty.clone())).zip(tokens), checksum);
+	}
+
+	let result = types
+		.iter()
+		.zip(tokens.iter())
+		.map(|(ty, to)| format!("{ty} {}", to.display(checksum)))
+		.collect::<Vec<String>>()
+		.join("\n");
 
 	Ok(result)
 }
 
-fn decode_log(path: &str, name_or_signature: &str, topics: &[String], data: &str) -> anyhow::Result<String> {
+/// Decodes call data with the first signature of the dictionary at `path` whose
+/// selector matches the one the data starts with and which decodes the data.
+fn decode_calldata(path: &str, data: &str, json: bool, checksum: bool, lenient: bool) -> anyhow::Result<String> {
+	let data = read_data(data)?;
+	let (selector, data) = split_selector(&data, "call data")?;
+
+	let dictionary = fs::read_to_string(path)?;
+	// Dictionaries such as 4byte dumps contain junk lines, which are skipped
+	// like candidates that fail to parse or to decode the data.
+	let candidates: Vec<_> = dictionary
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.filter_map(|line| line.split_once(','))
+		.filter(|(line_selector, _)| {
+			let line_selector = line_selector.trim();
+			hex::decode(line_selector.strip_prefix("0x").unwrap_or(line_selector)).is_ok_and(|s| s == selector)
+		})
+		.map(|(_, signature)| signature.trim())
+		.collect();
+
+	for signature in &candidates {
+		let function = match function_from_signature(signature) {
+			Ok(function) => function,
+			Err(_) => continue,
+		};
+		let inputs = function.input_param_types();
+		let tokens = match decode_data(&inputs, data, lenient) {
+			Ok(tokens) => tokens,
+			Err(_) => continue,
+		};
+
+		if json {
+			return decoded_to_json(inputs.iter().map(|ty| NamedParamType::new("", ty.clone())).zip(tokens), checksum);
+		}
+
+		let canonical = function.canonical_signature();
+		let params = inputs.iter().zip(tokens.iter()).map(|(ty, to)| format!("{ty} {}", to.display(checksum)));
+		return Ok(std::iter::once(canonical).chain(params).join("\n"));
+	}
+
+	match candidates.is_empty() {
+		true => Err(anyhow!("no signature found for selector 0x{}", hex::encode(selector))),
+		false => Err(anyhow!("no signature for selector 0x{} matches the data", hex::encode(selector))),
+	}
+}
+
+fn decode_log(
+	path: &str,
+	name_or_signature: &str,
+	topics: &[String],
+	data: &str,
+	json: bool,
+	checksum: bool,
+) -> anyhow::Result<String> {
 	let event = load_event(path, name_or_signature)?;
 	let topics: Vec<Hash> = topics.iter().map(|t| t.parse()).collect::<Result<_, _>>()?;
-	let data = hex::decode(data)?;
+	let data = read_data(data)?;
 	let decoded = event.parse_log((topics, data).into())?;
 
+	if json {
+		// Decoded log params are in the same order as the event inputs.
+		let params = event.inputs.iter().map(|input| NamedParamType::new(input.name.clone(), input.kind.clone()));
+		return decoded_to_json(params.zip(decoded.params.into_iter().map(|param| param.value)), checksum);
+	}
+
 	let result = decoded
 		.params
 		.into_iter()
-		.map(|log_param| format!("{} {}", log_param.name, log_param.value))
+		.map(|log_param| format!("{} {}", log_param.name, log_param.value.display(checksum)))
 		.collect::<Vec<String>>()
 		.join("\n");
 
 	Ok(result)
 }
 
+/// Formats decoded values as a JSON array of named tokens, optionally with
+/// EIP-55 checksummed addresses.
+fn decoded_to_json(decoded: impl Iterator<Item = (NamedParamType, Token)>, checksum: bool) -> anyhow::Result<String> {
+	let tokens = decoded.map(|(param, value)| NamedToken::new(param, value)).collect::<Result<Vec<_>, _>>()?;
+	match checksum {
+		true => Ok(serde_json::to_string(&tokens.iter().map(NamedToken::checksummed).collect::<Vec<_>>())?),
+		false => Ok(serde_json::to_string(&tokens)?),
+	}
+}
+
 fn hash_signature(sig: &str) -> Hash {
 	Hash::from_slice(Keccak256::digest(sig.replace(' ', "").as_bytes()).as_slice())
 }
@@ -248,6 +462,25 @@ fn hash_signature(sig: &str) -> Hash {
 #[cfg(test)]
 mod tests {
 	use super::execute;
+	use std::{fs, path::PathBuf};
+
+	/// A file in the temp dir, removed when dropped. The process id keeps
+	/// concurrent test runs from sharing files.
+	struct TempFile(PathBuf);
+
+	impl TempFile {
+		fn new(name: &str, contents: impl AsRef<[u8]>) -> Self {
+			let path = std::env::temp_dir().join(format!("ethabi-cli-{}-{name}", std::process::id()));
+			fs::write(&path, contents).unwrap();
+			TempFile(path)
+		}
+	}
+
+	impl Drop for TempFile {
+		fn drop(&mut self) {
+			let _ = fs::remove_file(&self.0);
+		}
+	}
 
 	#[test]
 	fn simple_encode() {
@@ -377,6 +610,22 @@ mod tests {
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
+	#[test]
+	fn signature_encode() {
+		let command = "ethabi encode signature foo(bool) -p 1".split(' ');
+		let expected = "455575780000000000000000000000000000000000000000000000000000000000000001";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = "ethabi encode signature bar(string):(uint256) -p 1".split(' ');
+		let expected = "d473a8ed0000000000000000000000000000000000000000000000000000000000000020\
+		                000000000000000000000000000000000000000000000000000000000000000131000000\
+		                00000000000000000000000000000000000000000000000000000000";
+		assert_eq!(execute(command).unwrap(), expected);
+
+		let command = vec!["ethabi", "encode", "signature", "bar(string) returns (uint256)", "-p", "1"];
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
 	#[test]
 	fn simple_decode() {
 		let command =
@@ -389,7 +638,7 @@ mod tests {
 	fn int_decode() {
 		let command = "ethabi decode params -t int256 fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"
 			.split(' ');
-		let expected = "int256 fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe";
+		let expected = "int256 -2";
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
@@ -409,6 +658,118 @@ bool false";
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
+	#[test]
+	fn json_decode() {
+		let command = "ethabi decode params -t bool -t string -t bool 00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096761766f66796f726b0000000000000000000000000000000000000000000000 --json".split(' ');
+		let expected = r#"[{"name":"","type":"bool","value":true},{"name":"","type":"string","value":"gavofyork"},{"name":"","type":"bool","value":false}]"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn json_decode_nested() {
+		let command = "ethabi decode params -t (address,uint256,int256)[] -t bytes2 --json 00000000000000000000000000000000000000000000000000000000000000401234000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000011111111111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000100fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe".split(' ');
+		let expected = r#"[{"name":"","type":"tuple[]","components":[{"name":"","type":"address"},{"name":"","type":"uint256"},{"name":"","type":"int256"}],"value":[["0x1111111111111111111111111111111111111111","256","-2"]]},{"name":"","type":"bytes2","value":"0x1234"}]"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn checksum_decode() {
+		let data = "000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000010000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed0000000000000000000000000000000000000000000000000000000000000001";
+		let command = format!("ethabi decode params -t (address,uint256)[] {data} --checksum");
+		let expected = "(address,uint256)[] [(0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,1)]";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+
+		let command = format!("ethabi decode params -t (address,uint256)[] {data} --checksum --json");
+		let expected = r#"[{"name":"","type":"tuple[]","components":[{"name":"","type":"address"},{"name":"","type":"uint256"}],"value":[["0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed","1"]]}]"#;
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+
+		let command = format!("ethabi decode params -t (address,uint256)[] {data}");
+		let expected = "(address,uint256)[] [(0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed,1)]";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+	}
+
+	#[test]
+	fn lenient_decode() {
+		let data = "000000000000000000000000000000000000000000000000000000000000000112";
+		let command = format!("ethabi decode params -t uint256 -t bytes32 {data}");
+		assert!(execute(command.split(' ')).is_err());
+
+		let command = format!("ethabi decode params -t uint256 -t bytes32 {data} --lenient");
+		let expected = "uint256 1\nbytes32 0x1200000000000000000000000000000000000000000000000000000000000000";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+	}
+
+	#[test]
+	fn json_log_decode() {
+		let command = "ethabi decode log ../res/event.abi Event --json -l 0000000000000000000000000000000000000000000000000000000000000001 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
+		let expected = r#"[{"name":"a","type":"bool","value":true},{"name":"b","type":"address","value":"0x4444444444444444444444444444444444444444"}]"#;
+		assert_eq!(execute(command).unwrap(), expected);
+	}
+
+	#[test]
+	fn file_decode() {
+		let data = "0000000000000000000000000000000000000000000000000000000000000001";
+		let hex_file = TempFile::new("file-decode.hex", format!("0x{data}\n"));
+		let bin_file = TempFile::new("file-decode.bin", hex::decode(data).unwrap());
+
+		let command = format!("ethabi decode params -t bool @{}", hex_file.0.display());
+		assert_eq!(execute(command.split(' ')).unwrap(), "bool true");
+		let command = format!("ethabi decode params -t bool @{}", bin_file.0.display());
+		assert_eq!(execute(command.split(' ')).unwrap(), "bool true");
+	}
+
+	#[test]
+	fn calldata_decode() {
+		let dictionary = TempFile::new(
+			"calldata-decode.txt",
+			"# selector,signature\n\
+			 0x23b872dd,transferFrom(address,address,uint256)\n\
+			 not a dictionary line\n\
+			 zzzzzzzz,junk()\n\
+			 a9059cbb,transfer(\n\
+			 a9059cbb,transfer(string)\n\
+			 a9059cbb,transfer(address,uint256)\n",
+		);
+		let data = "a9059cbb\
+		            0000000000000000000000001111111111111111111111111111111111111111\
+		            0000000000000000000000000000000000000000000000000000000000000001";
+
+		// junk lines are skipped and `transfer(string)` shares the selector but can't decode the data
+		let command = format!("ethabi decode calldata --signatures {} {data}", dictionary.0.display());
+		let expected = "transfer(address,uint256)\n\
+		                address 0x1111111111111111111111111111111111111111\n\
+		                uint256 1";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+
+		let command = format!("ethabi decode calldata --signatures {} {data} --json", dictionary.0.display());
+		let expected = r#"[{"name":"","type":"address","value":"0x1111111111111111111111111111111111111111"},{"name":"","type":"uint256","value":"1"}]"#;
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+
+		let command = format!("ethabi decode calldata --signatures {} 095ea7b3", dictionary.0.display());
+		assert!(execute(command.split(' ')).is_err());
+	}
+
+	#[test]
+	fn tuple_array_encode_decode() {
+		let encoded = "0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200000000000000000000000011111111111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000001000000000000000000000000222222222222222222222222222222222222222200000000000000000000000000000000000000000000000000000000000000ff";
+
+		let command = "ethabi encode params -v (address,uint256)[] [(0x1111111111111111111111111111111111111111,1),(0x2222222222222222222222222222222222222222,255)] --lenient".split(' ');
+		assert_eq!(execute(command).unwrap(), encoded);
+
+		let command = format!("ethabi decode params -t (address,uint256)[] {encoded}");
+		let expected = "(address,uint256)[] [(0x1111111111111111111111111111111111111111,1),(0x2222222222222222222222222222222222222222,255)]";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+	}
+
+	#[test]
+	fn nested_tuple_array_decode() {
+		let encoded = "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000060222222222222222222222222222222222222222222222222222222222222222200000000000000000000000000000000000000000000000000000000000001a0000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000020000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000002123400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000025678000000000000000000000000000000000000000000000000000000000000";
+
+		let command = format!("ethabi decode params -t ((uint256,(address,bytes)[])[],bytes32,bytes) {encoded}");
+		let expected = "((uint256,(address,bytes)[])[],bytes32,bytes) ([(1,[(0x1111111111111111111111111111111111111111,0x1234)])],0x2222222222222222222222222222222222222222222222222222222222222222,0x5678)";
+		assert_eq!(execute(command.split(' ')).unwrap(), expected);
+	}
+
 	#[test]
 	fn abi_decode() {
 		let command = "ethabi decode function ../res/foo.abi bar 0000000000000000000000000000000000000000000000000000000000000001".split(' ');
@@ -420,7 +781,7 @@ bool false";
 	fn log_decode() {
 		let command = "ethabi decode log ../res/event.abi Event -l 0000000000000000000000000000000000000000000000000000000000000001 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
 		let expected = "a true
-b 4444444444444444444444444444444444444444";
+b 0x4444444444444444444444444444444444444444";
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
@@ -428,7 +789,7 @@ b 4444444444444444444444444444444444444444";
 	fn log_decode_signature() {
 		let command = "ethabi decode log ../res/event.abi Event(bool,address) -l 0000000000000000000000000000000000000000000000000000000000000001 0000000000000000000000004444444444444444444444444444444444444444".split(' ');
 		let expected = "a true
-b 4444444444444444444444444444444444444444";
+b 0x4444444444444444444444444444444444444444";
 		assert_eq!(execute(command).unwrap(), expected);
 	}
 
@@ -6,9 +6,17 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+/// Generates a module with the rust interface of the contract whose abi is at `$path`.
+///
+/// Code generation options can follow the path, such as `bytes20_as_h160 = "true"` to
+/// represent `bytes20` values as `H160` instead of `[u8; 20]`, or
+/// `uint_type = "my::U256"` to use another type for unsigned integers. That type is
+/// converted from and into `ethabi::Uint` with `From`, or with the functions given by
+/// `uint_from = "my::u256_from_uint"` and `uint_into = "my::u256_into_uint"` for types
+/// which can't implement `From`, such as types of other crates.
 #[macro_export]
 macro_rules! use_contract {
-	($module: ident, $path: expr) => {
+	($module: ident, $path: expr $(, $option: ident = $value: expr)* $(,)?) => {
 		#[allow(dead_code)]
 		#[allow(missing_docs)]
 		#[allow(unused_imports)]
@@ -16,7 +24,7 @@ macro_rules! use_contract {
 		#[allow(unused_variables)]
 		pub mod $module {
 			#[derive(ethabi_derive::EthabiContract)]
-			#[ethabi_contract_options(path = $path)]
+			#[ethabi_contract_options(path = $path $(, $option = $value)*)]
 			struct _Dummy;
 		}
 	};
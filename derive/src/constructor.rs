@@ -10,7 +10,8 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use super::{
-	from_template_param, get_template_names, input_names, rust_type, template_param_type, to_ethabi_param_vec, to_token,
+	from_template_param, get_template_names, input_names, rust_type, template_param_type, to_ethabi_param_vec,
+	to_state_mutability, to_token, Options,
 };
 
 /// Structure used to generate contract's constructor interface.
@@ -19,19 +20,31 @@ pub struct Constructor {
 	inputs_definitions: Vec<TokenStream>,
 	tokenize: Vec<TokenStream>,
 	recreate_inputs: TokenStream,
+	state_mutability: TokenStream,
 }
 
 impl<'a> From<&'a ethabi::Constructor> for Constructor {
 	fn from(c: &'a ethabi::Constructor) -> Self {
+		Constructor::new(c, &Options::default())
+	}
+}
+
+impl Constructor {
+	/// Prepares the generation of a contract constructor's interface with the given code generation options.
+	pub fn new(c: &ethabi::Constructor, options: &Options) -> Self {
 		// [param0, hello_world, param2]
 		let input_names = input_names(&c.inputs);
 
 		// [T0: Into<Uint>, T1: Into<Bytes>, T2: IntoIterator<Item = U2>, U2 = Into<Uint>]
-		let inputs_declarations =
-			c.inputs.iter().enumerate().map(|(index, param)| template_param_type(&param.kind, index)).collect();
+		let inputs_declarations = c
+			.inputs
+			.iter()
+			.enumerate()
+			.map(|(index, param)| template_param_type(&param.kind, index, options))
+			.collect();
 
 		// [Uint, Bytes, Vec<Uint>]
-		let kinds: Vec<_> = c.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+		let kinds: Vec<_> = c.inputs.iter().map(|param| rust_type(&param.kind, options)).collect();
 
 		// [T0, T1, T2]
 		let template_names: Vec<_> = get_template_names(&kinds);
@@ -48,7 +61,7 @@ impl<'a> From<&'a ethabi::Constructor> for Constructor {
 		let tokenize: Vec<_> = input_names
 			.iter()
 			.zip(c.inputs.iter())
-			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind))
+			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind, options))
 			.collect();
 
 		Constructor {
@@ -56,23 +69,24 @@ impl<'a> From<&'a ethabi::Constructor> for Constructor {
 			inputs_definitions,
 			tokenize,
 			recreate_inputs: to_ethabi_param_vec(&c.inputs),
+			state_mutability: to_state_mutability(c.state_mutability),
 		}
 	}
-}
 
-impl Constructor {
 	/// Generates contract constructor interface.
 	pub fn generate(&self) -> TokenStream {
 		let declarations = &self.inputs_declarations;
 		let definitions = &self.inputs_definitions;
 		let tokenize = &self.tokenize;
 		let recreate_inputs = &self.recreate_inputs;
+		let state_mutability = &self.state_mutability;
 
 		quote! {
 			/// Encodes a call to contract's constructor.
 			pub fn constructor<#(#declarations),*>(#(#definitions),*) -> ethabi::Bytes {
 				let c = ethabi::Constructor {
 					inputs: #recreate_inputs,
+					state_mutability: #state_mutability,
 				};
 				let tokens = vec![#(#tokenize),*];
 				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
@@ -88,7 +102,8 @@ mod tests {
 
 	#[test]
 	fn test_no_params() {
-		let ethabi_constructor = ethabi::Constructor { inputs: vec![] };
+		let ethabi_constructor =
+			ethabi::Constructor { inputs: vec![], state_mutability: ethabi::StateMutability::Payable };
 
 		let c = Constructor::from(&ethabi_constructor);
 
@@ -97,6 +112,7 @@ mod tests {
 			pub fn constructor<>(code: ethabi::Bytes) -> ethabi::Bytes {
 				let c = ethabi::Constructor {
 					inputs: vec![],
+					state_mutability: ::ethabi::StateMutability::Payable,
 				};
 				let tokens = vec![];
 				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
@@ -109,7 +125,13 @@ mod tests {
 	#[test]
 	fn test_one_param() {
 		let ethabi_constructor = ethabi::Constructor {
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Uint(256), internal_type: None }],
+			inputs: vec![ethabi::Param {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Uint(256),
+				internal_type: None,
+				components: vec![],
+			}],
+			state_mutability: ethabi::StateMutability::NonPayable,
 		};
 
 		let c = Constructor::from(&ethabi_constructor);
@@ -121,8 +143,10 @@ mod tests {
 					inputs: vec![ethabi::Param {
 						name: "foo".to_owned(),
 						kind: ethabi::ParamType::Uint(256usize),
-						internal_type: None
+						internal_type: None,
+						components: vec![]
 					}],
+					state_mutability: ::ethabi::StateMutability::NonPayable,
 				};
 				let tokens = vec![ethabi::Token::Uint(foo.into())];
 				c.encode_input(code, &tokens).expect(INTERNAL_ERR)
@@ -9,7 +9,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::{constructor::Constructor, event::Event, function::Function};
+use crate::{constructor::Constructor, event::Event, function::Function, Options};
 
 /// Structure used to generate rust interface for solidity contract.
 pub struct Contract {
@@ -20,15 +20,20 @@ pub struct Contract {
 
 impl<'a> From<&'a ethabi::Contract> for Contract {
 	fn from(c: &'a ethabi::Contract) -> Self {
-		Contract {
-			constructor: c.constructor.as_ref().map(Into::into),
-			functions: c.functions().map(Into::into).collect(),
-			events: c.events().map(Into::into).collect(),
-		}
+		Contract::new(c, &Options::default())
 	}
 }
 
 impl Contract {
+	/// Prepares the generation of a contract's rust interface with the given code generation options.
+	pub fn new(c: &ethabi::Contract, options: &Options) -> Self {
+		Contract {
+			constructor: c.constructor.as_ref().map(|constructor| Constructor::new(constructor, options)),
+			functions: c.functions_sorted().into_iter().map(|function| Function::new(function, options)).collect(),
+			events: c.events_sorted().into_iter().map(|event| Event::new(event, options)).collect(),
+		}
+	}
+
 	/// Generates rust interface for a contract.
 	pub fn generate(&self) -> TokenStream {
 		let constructor = self.constructor.as_ref().map(Constructor::generate);
@@ -78,6 +83,7 @@ mod test {
 			errors: Default::default(),
 			receive: false,
 			fallback: false,
+			payable_fallback: false,
 		};
 
 		let c = Contract::from(&ethabi_contract);
@@ -10,7 +10,7 @@ use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 
-use super::{from_token, get_template_names, rust_type, to_syntax_string, to_token};
+use super::{from_token, get_template_names, rust_type, to_syntax_string, to_token, Options};
 
 /// Structure used to generate contract's event interface.
 pub struct Event {
@@ -27,6 +27,13 @@ pub struct Event {
 
 impl<'a> From<&'a ethabi::Event> for Event {
 	fn from(e: &'a ethabi::Event) -> Self {
+		Event::new(e, &Options::default())
+	}
+}
+
+impl Event {
+	/// Prepares the generation of a contract event's interface with the given code generation options.
+	pub fn new(e: &ethabi::Event, options: &Options) -> Self {
 		let names: Vec<_> = e
 			.inputs
 			.iter()
@@ -43,19 +50,19 @@ impl<'a> From<&'a ethabi::Event> for Event {
 				}
 			})
 			.collect();
-		let kinds: Vec<_> = e.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+		let kinds: Vec<_> = e.inputs.iter().map(|param| rust_type(&param.kind, options)).collect();
 		let log_fields =
 			names.iter().zip(kinds.iter()).map(|(param_name, kind)| quote! { pub #param_name: #kind }).collect();
 
 		let log_iter = quote! { log.next().expect(INTERNAL_ERR).value };
 
-		let to_log: Vec<_> = e.inputs.iter().map(|param| from_token(&param.kind, &log_iter)).collect();
+		let to_log: Vec<_> = e.inputs.iter().map(|param| from_token(&param.kind, &log_iter, options)).collect();
 
 		let log_init =
 			names.iter().zip(to_log.iter()).map(|(param_name, convert)| quote! { #param_name: #convert }).collect();
 
 		let topic_kinds: Vec<_> =
-			e.inputs.iter().filter(|param| param.indexed).map(|param| rust_type(&param.kind)).collect();
+			e.inputs.iter().filter(|param| param.indexed).map(|param| rust_type(&param.kind, options)).collect();
 		let topic_names: Vec<_> = e
 			.inputs
 			.iter()
@@ -92,11 +99,11 @@ impl<'a> From<&'a ethabi::Event> for Event {
 			.iter()
 			.zip(e.inputs.iter().filter(|p| p.indexed))
 			.enumerate()
-			.take(3)
+			.take(if e.anonymous { 4 } else { 3 })
 			.map(|(index, (param_name, param))| {
 				let topic = syn::Ident::new(&format!("topic{index}"), Span::call_site());
 				let i = quote! { i };
-				let to_token = to_token(&i, &param.kind);
+				let to_token = to_token(&i, &param.kind, options);
 				quote! { #topic: #param_name.into().map(|#i| #to_token), }
 			})
 			.collect();
@@ -113,7 +120,8 @@ impl<'a> From<&'a ethabi::Event> for Event {
 					ethabi::EventParam {
 						name: #name.to_owned(),
 						kind: #kind,
-						indexed: #indexed
+						indexed: #indexed,
+						components: vec![]
 					}
 				}
 			})
@@ -132,9 +140,7 @@ impl<'a> From<&'a ethabi::Event> for Event {
 			filter_init,
 		}
 	}
-}
 
-impl Event {
 	/// Generates event log struct.
 	pub fn generate_log(&self) -> TokenStream {
 		let name = syn::Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
@@ -268,7 +274,12 @@ mod tests {
 	fn test_event_with_one_input() {
 		let ethabi_event = ethabi::Event {
 			name: "one".into(),
-			inputs: vec![ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: true }],
+			inputs: vec![ethabi::EventParam {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				indexed: true,
+				components: vec![],
+			}],
 			anonymous: false,
 		};
 
@@ -285,7 +296,8 @@ mod tests {
 						inputs: vec![ethabi::EventParam {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::Address,
-							indexed: true
+							indexed: true,
+							components: vec![]
 						}],
 						anonymous: false,
 					}
@@ -323,7 +335,12 @@ mod tests {
 	fn test_log_with_one_field() {
 		let ethabi_event = ethabi::Event {
 			name: "one".into(),
-			inputs: vec![ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: false }],
+			inputs: vec![ethabi::EventParam {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				indexed: false,
+				components: vec![],
+			}],
 			anonymous: false,
 		};
 
@@ -344,13 +361,24 @@ mod tests {
 		let ethabi_event = ethabi::Event {
 			name: "many".into(),
 			inputs: vec![
-				ethabi::EventParam { name: "foo".into(), kind: ethabi::ParamType::Address, indexed: false },
+				ethabi::EventParam {
+					name: "foo".into(),
+					kind: ethabi::ParamType::Address,
+					indexed: false,
+					components: vec![],
+				},
 				ethabi::EventParam {
 					name: "bar".into(),
 					kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::String)),
 					indexed: false,
+					components: vec![],
+				},
+				ethabi::EventParam {
+					name: "xyz".into(),
+					kind: ethabi::ParamType::Uint(256),
+					indexed: false,
+					components: vec![],
 				},
-				ethabi::EventParam { name: "xyz".into(), kind: ethabi::ParamType::Uint(256), indexed: false },
 			],
 			anonymous: false,
 		};
@@ -12,7 +12,7 @@ use quote::quote;
 
 use super::{
 	from_template_param, from_token, get_output_kinds, get_template_names, input_names, rust_type, template_param_type,
-	to_ethabi_param_vec, to_token,
+	to_ethabi_param_vec, to_state_mutability, to_token, Options,
 };
 
 struct TemplateParam {
@@ -70,14 +70,22 @@ pub struct Function {
 
 impl<'a> From<&'a ethabi::Function> for Function {
 	fn from(f: &'a ethabi::Function) -> Self {
+		Function::new(f, &Options::default())
+	}
+}
+
+impl Function {
+	/// Prepares the generation of a contract function's interface with the given code generation options.
+	pub fn new(f: &ethabi::Function, options: &Options) -> Self {
 		// [param0, hello_world, param2]
 		let input_names = input_names(&f.inputs);
 
 		// [T0: Into<Uint>, T1: Into<Bytes>, T2: IntoIterator<Item = U2>, U2 = Into<Uint>]
-		let declarations = f.inputs.iter().enumerate().map(|(index, param)| template_param_type(&param.kind, index));
+		let declarations =
+			f.inputs.iter().enumerate().map(|(index, param)| template_param_type(&param.kind, index, options));
 
 		// [Uint, Bytes, Vec<Uint>]
-		let kinds: Vec<_> = f.inputs.iter().map(|param| rust_type(&param.kind)).collect();
+		let kinds: Vec<_> = f.inputs.iter().map(|param| rust_type(&param.kind, options)).collect();
 
 		// [T0, T1, T2]
 		let template_names: Vec<_> = get_template_names(&kinds);
@@ -97,10 +105,10 @@ impl<'a> From<&'a ethabi::Function> for Function {
 		let tokenize: Vec<_> = input_names
 			.iter()
 			.zip(f.inputs.iter())
-			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind))
+			.map(|(param_name, param)| to_token(&from_template_param(&param.kind, param_name), &param.kind, options))
 			.collect();
 
-		let output_result = get_output_kinds(&f.outputs);
+		let output_result = get_output_kinds(&f.outputs, options);
 
 		let output_implementation = match f.outputs.len() {
 			0 => quote! {
@@ -109,7 +117,7 @@ impl<'a> From<&'a ethabi::Function> for Function {
 			},
 			1 => {
 				let o = quote! { out };
-				let from_first = from_token(&f.outputs[0].kind, &o);
+				let from_first = from_token(&f.outputs[0].kind, &o, options);
 				quote! {
 					let out = self.0.decode_output(output)?.into_iter().next().expect(INTERNAL_ERR);
 					Ok(#from_first)
@@ -117,7 +125,7 @@ impl<'a> From<&'a ethabi::Function> for Function {
 			}
 			_ => {
 				let o = quote! { out.next().expect(INTERNAL_ERR) };
-				let outs: Vec<_> = f.outputs.iter().map(|param| from_token(&param.kind, &o)).collect();
+				let outs: Vec<_> = f.outputs.iter().map(|param| from_token(&param.kind, &o, options)).collect();
 
 				quote! {
 					let mut out = self.0.decode_output(output)?.into_iter();
@@ -142,9 +150,7 @@ impl<'a> From<&'a ethabi::Function> for Function {
 			state_mutability: f.state_mutability,
 		}
 	}
-}
 
-impl Function {
 	/// Generates the interface for contract's function.
 	pub fn generate(&self) -> TokenStream {
 		let name = &self.name;
@@ -156,12 +162,7 @@ impl Function {
 		let recreate_outputs = &self.outputs.recreate_quote;
 		#[allow(deprecated)]
 		let constant = self.constant;
-		let state_mutability = match self.state_mutability {
-			ethabi::StateMutability::Pure => quote! { ::ethabi::StateMutability::Pure },
-			ethabi::StateMutability::Payable => quote! { ::ethabi::StateMutability::Payable },
-			ethabi::StateMutability::NonPayable => quote! { ::ethabi::StateMutability::NonPayable },
-			ethabi::StateMutability::View => quote! { ::ethabi::StateMutability::View },
-		};
+		let state_mutability = to_state_mutability(self.state_mutability);
 		let outputs_result = &self.outputs.result;
 		let outputs_implementation = &self.outputs.implementation;
 
@@ -288,11 +289,17 @@ mod tests {
 		#[allow(deprecated)]
 		let ethabi_function = ethabi::Function {
 			name: "hello".into(),
-			inputs: vec![ethabi::Param { name: "foo".into(), kind: ethabi::ParamType::Address, internal_type: None }],
+			inputs: vec![ethabi::Param {
+				name: "foo".into(),
+				kind: ethabi::ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
 			outputs: vec![ethabi::Param {
 				name: "bar".into(),
 				kind: ethabi::ParamType::Uint(256),
 				internal_type: None,
+				components: vec![],
 			}],
 			constant: None,
 			state_mutability: ethabi::StateMutability::Payable,
@@ -311,12 +318,14 @@ mod tests {
 						inputs: vec![ethabi::Param {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::Address,
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						outputs: vec![ethabi::Param {
 							name: "bar".to_owned(),
 							kind: ethabi::ParamType::Uint(256usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						constant: Some(false),
 						state_mutability: ::ethabi::StateMutability::Payable
@@ -369,16 +378,28 @@ mod tests {
 					name: "foo".into(),
 					kind: ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address), 2),
 					internal_type: None,
+					components: vec![],
 				},
 				ethabi::Param {
 					name: "bar".into(),
 					kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256))),
 					internal_type: None,
+					components: vec![],
 				},
 			],
 			outputs: vec![
-				ethabi::Param { name: "".into(), kind: ethabi::ParamType::Uint(256), internal_type: None },
-				ethabi::Param { name: "".into(), kind: ethabi::ParamType::String, internal_type: None },
+				ethabi::Param {
+					name: "".into(),
+					kind: ethabi::ParamType::Uint(256),
+					internal_type: None,
+					components: vec![],
+				},
+				ethabi::Param {
+					name: "".into(),
+					kind: ethabi::ParamType::String,
+					internal_type: None,
+					components: vec![],
+				},
 			],
 			constant: None,
 			state_mutability: ethabi::StateMutability::Payable,
@@ -397,20 +418,24 @@ mod tests {
 						inputs: vec![ethabi::Param {
 							name: "foo".to_owned(),
 							kind: ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::Address), 2usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}, ethabi::Param {
 							name: "bar".to_owned(),
 							kind: ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256usize))),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						outputs: vec![ethabi::Param {
 							name: "".to_owned(),
 							kind: ethabi::ParamType::Uint(256usize),
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}, ethabi::Param {
 							name: "".to_owned(),
 							kind: ethabi::ParamType::String,
-							internal_type: None
+							internal_type: None,
+							components: vec![]
 						}],
 						constant: Some(false),
 						state_mutability: ::ethabi::StateMutability::Payable
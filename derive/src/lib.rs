@@ -23,23 +23,90 @@ use std::{borrow::Cow, env, fs, path::PathBuf};
 
 const ERROR_MSG: &str = "`derive(EthabiContract)` failed";
 
+/// Code generation options, set through `ethabi_contract_options`.
+#[derive(Debug, Default, Clone)]
+struct Options {
+	/// Represent `bytes20` values as `H160` instead of `[u8; 20]`.
+	bytes20_as_h160: bool,
+	/// Type used instead of `ethabi::Uint` for unsigned integers.
+	uint_type: Option<proc_macro2::TokenStream>,
+	/// Function converting an `ethabi::Uint` into `uint_type`, `From` by default.
+	uint_from: Option<proc_macro2::TokenStream>,
+	/// Function converting `uint_type` into an `ethabi::Uint`, `Into` by default.
+	uint_into: Option<proc_macro2::TokenStream>,
+}
+
 #[proc_macro_derive(EthabiContract, attributes(ethabi_contract_options))]
 pub fn ethabi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let ast = syn::parse(input).expect(ERROR_MSG);
-	let gen = impl_ethabi_derive(&ast).expect(ERROR_MSG);
-	gen.into()
+	match impl_ethabi_derive(&ast) {
+		Ok(gen) => gen.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+fn impl_ethabi_derive(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+	let (contract, options) = load_contract(ast).map_err(derive_error)?;
+	check_supported_types(&contract)?;
+	let c = contract::Contract::new(&contract, &options);
+	Ok(c.generate())
 }
 
-fn impl_ethabi_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream> {
+fn derive_error(err: impl std::fmt::Display) -> syn::Error {
+	syn::Error::new(Span::call_site(), format!("{ERROR_MSG}: {err}"))
+}
+
+fn load_contract(ast: &syn::DeriveInput) -> Result<(Contract, Options)> {
 	let options = get_options(&ast.attrs, "ethabi_contract_options")?;
-	let path = get_option(&options, "path")?;
+	let path =
+		get_string_option(&options, "path")?.ok_or(Error::Other(Cow::Borrowed("Expected to find option path")))?;
 	let normalized_path = normalize_path(&path)?;
 	let source_file = fs::File::open(&normalized_path).map_err(|_| {
 		Error::Other(Cow::Owned(format!("Cannot load contract abi from `{}`", normalized_path.display())))
 	})?;
 	let contract = Contract::load(source_file)?;
-	let c = contract::Contract::from(&contract);
-	Ok(c.generate())
+	let codegen_options = Options {
+		bytes20_as_h160: get_bool_option(&options, "bytes20_as_h160")?,
+		uint_type: get_parsed_option::<syn::Type>(&options, "uint_type")?,
+		uint_from: get_parsed_option::<syn::Path>(&options, "uint_from")?,
+		uint_into: get_parsed_option::<syn::Path>(&options, "uint_into")?,
+	};
+	if codegen_options.uint_type.is_none()
+		&& (codegen_options.uint_from.is_some() || codegen_options.uint_into.is_some())
+	{
+		return Err(Error::Other(Cow::Borrowed("`uint_from` and `uint_into` require `uint_type`")));
+	}
+	Ok((contract, codegen_options))
+}
+
+/// Rejects contracts using types which code generation can't handle yet, so
+/// that the error names the offending function or event.
+fn check_supported_types(contract: &Contract) -> syn::Result<()> {
+	fn check<'a>(params: impl IntoIterator<Item = &'a ParamType>, what: &str, location: String) -> syn::Result<()> {
+		match params.into_iter().find(|kind| contains_tuple(kind)) {
+			Some(kind) => Err(derive_error(format_args!(
+				"ethabi-derive does not support tuple {what} yet in {location} (found `{kind}`)"
+			))),
+			None => Ok(()),
+		}
+	}
+
+	fn contains_tuple(kind: &ParamType) -> bool {
+		kind.components().is_some() || kind.element().is_some_and(contains_tuple)
+	}
+
+	if let Some(constructor) = &contract.constructor {
+		check(constructor.inputs.iter().map(|param| &param.kind), "inputs", "constructor".into())?;
+	}
+	for function in contract.functions() {
+		let location = format!("function `{}`", function.name);
+		check(function.inputs.iter().map(|param| &param.kind), "inputs", location.clone())?;
+		check(function.outputs.iter().map(|param| &param.kind), "outputs", location)?;
+	}
+	for event in contract.events() {
+		check(event.inputs.iter().map(|param| &param.kind), "params", format!("event `{}`", event.name))?;
+	}
+	Ok(())
 }
 
 fn get_options(attrs: &[syn::Attribute], name: &str) -> Result<Vec<syn::NestedMeta>> {
@@ -51,27 +118,51 @@ fn get_options(attrs: &[syn::Attribute], name: &str) -> Result<Vec<syn::NestedMe
 	}
 }
 
-fn get_option(options: &[syn::NestedMeta], name: &str) -> Result<String> {
+/// Returns the value of the option `name`, or `None` if it isn't set.
+fn get_option<'a>(options: &'a [syn::NestedMeta], name: &str) -> Result<Option<&'a syn::Lit>> {
 	let item = options
 		.iter()
 		.flat_map(|nested| match *nested {
 			syn::NestedMeta::Meta(ref meta) => Some(meta),
 			_ => None,
 		})
-		.find(|meta| meta.path().is_ident(name))
-		.ok_or_else(|| Error::Other(Cow::Owned(format!("Expected to find option {name}"))))?;
+		.find(|meta| meta.path().is_ident(name));
 
-	str_value_of_meta_item(item, name)
+	match item {
+		Some(syn::Meta::NameValue(name_value)) => Ok(Some(&name_value.lit)),
+		Some(_) => Err(Error::Other(Cow::Owned(format!(r#"`{name}` must be in the form `#[{name}="something"]`"#)))),
+		None => Ok(None),
+	}
 }
 
-fn str_value_of_meta_item(item: &syn::Meta, name: &str) -> Result<String> {
-	if let syn::Meta::NameValue(ref name_value) = *item {
-		if let syn::Lit::Str(ref value) = name_value.lit {
-			return Ok(value.value());
-		}
+fn get_string_option(options: &[syn::NestedMeta], name: &str) -> Result<Option<String>> {
+	match get_option(options, name)? {
+		Some(syn::Lit::Str(value)) => Ok(Some(value.value())),
+		Some(_) => Err(Error::Other(Cow::Owned(format!(r#"`{name}` must be in the form `#[{name}="something"]`"#)))),
+		None => Ok(None),
 	}
+}
 
-	Err(Error::Other(Cow::Owned(format!(r#"`{name}` must be in the form `#[{name}="something"]`"#))))
+fn get_bool_option(options: &[syn::NestedMeta], name: &str) -> Result<bool> {
+	match get_string_option(options, name)?.as_deref() {
+		Some("true") => Ok(true),
+		Some("false") | None => Ok(false),
+		Some(_) => Err(Error::Other(Cow::Owned(format!(r#"`{name}` must be either "true" or "false""#)))),
+	}
+}
+
+/// Parses the option `name` as Rust syntax such as a type or path.
+fn get_parsed_option<T>(options: &[syn::NestedMeta], name: &str) -> Result<Option<proc_macro2::TokenStream>>
+where
+	T: syn::parse::Parse + quote::ToTokens,
+{
+	let value = match get_string_option(options, name)? {
+		Some(value) => value,
+		None => return Ok(None),
+	};
+	let parsed = syn::parse_str::<T>(&value)
+		.map_err(|_| Error::Other(Cow::Owned(format!("`{name}` must be valid Rust syntax, found `{value}`"))))?;
+	Ok(Some(quote! { #parsed }))
 }
 
 fn normalize_path(relative_path: &str) -> Result<PathBuf> {
@@ -101,7 +192,7 @@ fn to_syntax_string(param_type: &ethabi::ParamType) -> proc_macro2::TokenStream
 			quote! { ethabi::ParamType::FixedArray(Box::new(#param_type_quote), #x) }
 		}
 		ParamType::Tuple(_) => {
-			unimplemented!("Tuples are not supported. https://github.com/openethereum/ethabi/issues/175")
+			unreachable!("tuples are rejected by `check_supported_types`")
 		}
 	}
 }
@@ -119,7 +210,8 @@ where
 				ethabi::Param {
 					name: #name.to_owned(),
 					kind: #kind,
-					internal_type: None
+					internal_type: None,
+					components: vec![]
 				}
 			}
 		})
@@ -128,56 +220,75 @@ where
 	quote! { vec![ #(#p),* ] }
 }
 
-fn rust_type(input: &ParamType) -> proc_macro2::TokenStream {
+fn to_state_mutability(state_mutability: ethabi::StateMutability) -> proc_macro2::TokenStream {
+	match state_mutability {
+		ethabi::StateMutability::Pure => quote! { ::ethabi::StateMutability::Pure },
+		ethabi::StateMutability::Payable => quote! { ::ethabi::StateMutability::Payable },
+		ethabi::StateMutability::NonPayable => quote! { ::ethabi::StateMutability::NonPayable },
+		ethabi::StateMutability::View => quote! { ::ethabi::StateMutability::View },
+	}
+}
+
+fn rust_type(input: &ParamType, options: &Options) -> proc_macro2::TokenStream {
 	match *input {
 		ParamType::Address => quote! { ethabi::Address },
 		ParamType::Bytes => quote! { ethabi::Bytes },
 		ParamType::FixedBytes(32) => quote! { ethabi::Hash },
+		ParamType::FixedBytes(20) if options.bytes20_as_h160 => quote! { ethabi::ethereum_types::H160 },
 		ParamType::FixedBytes(size) => quote! { [u8; #size] },
 		ParamType::Int(_) => quote! { ethabi::Int },
-		ParamType::Uint(_) => quote! { ethabi::Uint },
+		ParamType::Uint(_) => match options.uint_type {
+			Some(ref uint_type) => uint_type.clone(),
+			None => quote! { ethabi::Uint },
+		},
 		ParamType::Bool => quote! { bool },
 		ParamType::String => quote! { String },
 		ParamType::Array(ref kind) => {
-			let t = rust_type(kind);
+			let t = rust_type(kind, options);
 			quote! { Vec<#t> }
 		}
 		ParamType::FixedArray(ref kind, size) => {
-			let t = rust_type(kind);
+			let t = rust_type(kind, options);
 			quote! { [#t, #size] }
 		}
 		ParamType::Tuple(_) => {
-			unimplemented!("Tuples are not supported. https://github.com/openethereum/ethabi/issues/175")
+			unreachable!("tuples are rejected by `check_supported_types`")
 		}
 	}
 }
 
-fn template_param_type(input: &ParamType, index: usize) -> proc_macro2::TokenStream {
+fn template_param_type(input: &ParamType, index: usize, options: &Options) -> proc_macro2::TokenStream {
 	let t_ident = syn::Ident::new(&format!("T{index}"), Span::call_site());
 	let u_ident = syn::Ident::new(&format!("U{index}"), Span::call_site());
 	match *input {
 		ParamType::Address => quote! { #t_ident: Into<ethabi::Address> },
 		ParamType::Bytes => quote! { #t_ident: Into<ethabi::Bytes> },
 		ParamType::FixedBytes(32) => quote! { #t_ident: Into<ethabi::Hash> },
+		ParamType::FixedBytes(20) if options.bytes20_as_h160 => {
+			quote! { #t_ident: Into<ethabi::ethereum_types::H160> }
+		}
 		ParamType::FixedBytes(size) => quote! { #t_ident: Into<[u8; #size]> },
 		ParamType::Int(_) => quote! { #t_ident: Into<ethabi::Int> },
-		ParamType::Uint(_) => quote! { #t_ident: Into<ethabi::Uint> },
+		ParamType::Uint(_) => {
+			let t = rust_type(input, options);
+			quote! { #t_ident: Into<#t> }
+		}
 		ParamType::Bool => quote! { #t_ident: Into<bool> },
 		ParamType::String => quote! { #t_ident: Into<String> },
 		ParamType::Array(ref kind) => {
-			let t = rust_type(kind);
+			let t = rust_type(kind, options);
 			quote! {
 				#t_ident: IntoIterator<Item = #u_ident>, #u_ident: Into<#t>
 			}
 		}
 		ParamType::FixedArray(ref kind, size) => {
-			let t = rust_type(kind);
+			let t = rust_type(kind, options);
 			quote! {
 				#t_ident: Into<[#u_ident; #size]>, #u_ident: Into<#t>
 			}
 		}
 		ParamType::Tuple(_) => {
-			unimplemented!("Tuples are not supported. https://github.com/openethereum/ethabi/issues/175")
+			unreachable!("tuples are rejected by `check_supported_types`")
 		}
 	}
 }
@@ -192,18 +303,29 @@ fn from_template_param(input: &ParamType, name: &syn::Ident) -> proc_macro2::Tok
 	}
 }
 
-fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::TokenStream {
+fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType, options: &Options) -> proc_macro2::TokenStream {
 	match *kind {
 		ParamType::Address => quote! { ethabi::Token::Address(#name) },
 		ParamType::Bytes => quote! { ethabi::Token::Bytes(#name) },
 		ParamType::FixedBytes(_) => quote! { ethabi::Token::FixedBytes(#name.as_ref().to_vec()) },
 		ParamType::Int(_) => quote! { ethabi::Token::Int(#name) },
-		ParamType::Uint(_) => quote! { ethabi::Token::Uint(#name) },
+		ParamType::Uint(_) => match options.uint_type {
+			Some(ref uint_type) => {
+				let into = options.uint_into.clone().unwrap_or_else(|| quote! { Into::<ethabi::Uint>::into });
+				quote! {
+					{
+						let value: #uint_type = #name;
+						ethabi::Token::Uint(#into(value))
+					}
+				}
+			}
+			None => quote! { ethabi::Token::Uint(#name) },
+		},
 		ParamType::Bool => quote! { ethabi::Token::Bool(#name) },
 		ParamType::String => quote! { ethabi::Token::String(#name) },
 		ParamType::Array(ref kind) => {
 			let inner_name = quote! { inner };
-			let inner_loop = to_token(&inner_name, kind);
+			let inner_loop = to_token(&inner_name, kind, options);
 			quote! {
 				// note the double {{
 				{
@@ -214,7 +336,7 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
 		}
 		ParamType::FixedArray(ref kind, _) => {
 			let inner_name = quote! { inner };
-			let inner_loop = to_token(&inner_name, kind);
+			let inner_loop = to_token(&inner_name, kind, options);
 			quote! {
 				// note the double {{
 				{
@@ -224,12 +346,12 @@ fn to_token(name: &proc_macro2::TokenStream, kind: &ParamType) -> proc_macro2::T
 			}
 		}
 		ParamType::Tuple(_) => {
-			unimplemented!("Tuples are not supported. https://github.com/openethereum/ethabi/issues/175")
+			unreachable!("tuples are rejected by `check_supported_types`")
 		}
 	}
 }
 
-fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream, options: &Options) -> proc_macro2::TokenStream {
 	match *kind {
 		ParamType::Address => quote! { #token.into_address().expect(INTERNAL_ERR) },
 		ParamType::Bytes => quote! { #token.into_bytes().expect(INTERNAL_ERR) },
@@ -241,6 +363,9 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
 				ethabi::Hash::from(result)
 			}
 		},
+		ParamType::FixedBytes(20) if options.bytes20_as_h160 => quote! {
+			ethabi::ethereum_types::H160::from_slice(&#token.into_fixed_bytes().expect(INTERNAL_ERR))
+		},
 		ParamType::FixedBytes(size) => {
 			let size: syn::Index = size.into();
 			quote! {
@@ -253,12 +378,18 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
 			}
 		}
 		ParamType::Int(_) => quote! { #token.into_int().expect(INTERNAL_ERR) },
-		ParamType::Uint(_) => quote! { #token.into_uint().expect(INTERNAL_ERR) },
+		ParamType::Uint(_) => match options.uint_type {
+			Some(ref uint_type) => {
+				let from = options.uint_from.clone().unwrap_or_else(|| quote! { <#uint_type>::from });
+				quote! { #from(#token.into_uint().expect(INTERNAL_ERR)) }
+			}
+			None => quote! { #token.into_uint().expect(INTERNAL_ERR) },
+		},
 		ParamType::Bool => quote! { #token.into_bool().expect(INTERNAL_ERR) },
 		ParamType::String => quote! { #token.into_string().expect(INTERNAL_ERR) },
 		ParamType::Array(ref kind) => {
 			let inner = quote! { inner };
-			let inner_loop = from_token(kind, &inner);
+			let inner_loop = from_token(kind, &inner, options);
 			quote! {
 				#token.into_array().expect(INTERNAL_ERR).into_iter()
 					.map(|#inner| #inner_loop)
@@ -267,7 +398,7 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
 		}
 		ParamType::FixedArray(ref kind, size) => {
 			let inner = quote! { inner };
-			let inner_loop = from_token(kind, &inner);
+			let inner_loop = from_token(kind, &inner, options);
 			let to_array = vec![quote! { iter.next() }; size];
 			quote! {
 				{
@@ -278,7 +409,7 @@ fn from_token(kind: &ParamType, token: &proc_macro2::TokenStream) -> proc_macro2
 			}
 		}
 		ParamType::Tuple(_) => {
-			unimplemented!("Tuples are not supported. https://github.com/openethereum/ethabi/issues/175")
+			unreachable!("tuples are rejected by `check_supported_types`")
 		}
 	}
 }
@@ -301,15 +432,15 @@ fn get_template_names(kinds: &[proc_macro2::TokenStream]) -> Vec<syn::Ident> {
 	kinds.iter().enumerate().map(|(index, _)| syn::Ident::new(&format!("T{index}"), Span::call_site())).collect()
 }
 
-fn get_output_kinds(outputs: &[Param]) -> proc_macro2::TokenStream {
+fn get_output_kinds(outputs: &[Param], options: &Options) -> proc_macro2::TokenStream {
 	match outputs.len() {
 		0 => quote! {()},
 		1 => {
-			let t = rust_type(&outputs[0].kind);
+			let t = rust_type(&outputs[0].kind, options);
 			quote! { #t }
 		}
 		_ => {
-			let outs: Vec<_> = outputs.iter().map(|param| rust_type(&param.kind)).collect();
+			let outs: Vec<_> = outputs.iter().map(|param| rust_type(&param.kind, options)).collect();
 			quote! { (#(#outs),*) }
 		}
 	}
@@ -325,3 +456,29 @@ fn rust_variable(name: &str) -> String {
 		other => other.to_snake_case(),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use ethabi::Contract;
+
+	use super::check_supported_types;
+
+	#[test]
+	fn test_unsupported_tuple_outputs() {
+		let json = r#"[{
+			"type": "function",
+			"name": "foo",
+			"inputs": [],
+			"outputs": [{ "name": "", "type": "tuple[]", "components": [{ "name": "a", "type": "bool" }] }],
+			"stateMutability": "view"
+		}]"#;
+		let contract = Contract::load(json.as_bytes()).unwrap();
+
+		let err = check_supported_types(&contract).unwrap_err();
+
+		assert_eq!(
+			err.to_string(),
+			"`derive(EthabiContract)` failed: ethabi-derive does not support tuple outputs yet in function `foo` (found `(bool)[]`)"
+		);
+	}
+}
@@ -0,0 +1,129 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Contract build artifact.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+#[cfg(feature = "full-serde")]
+use std::io;
+
+use serde::{Deserialize, Deserializer};
+
+#[cfg(feature = "full-serde")]
+use crate::errors;
+use crate::{Bytes, Contract};
+
+/// Build artifact of a contract, as produced by Hardhat, Truffle or Foundry.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Artifact {
+	/// Contract abi.
+	pub abi: Contract,
+	/// Creation bytecode, to be followed by the encoded constructor arguments.
+	///
+	/// `None` if missing, empty or not plain hex, e.g. for interfaces, abstract
+	/// contracts and bytecode with unlinked library placeholders (`__$…$__`).
+	#[serde(default, deserialize_with = "deserialize_bytecode")]
+	pub bytecode: Option<Bytes>,
+	/// Runtime bytecode of the deployed contract, `None` in the same cases as
+	/// [`bytecode`](Artifact::bytecode).
+	#[serde(rename = "deployedBytecode", default, deserialize_with = "deserialize_bytecode")]
+	pub deployed_bytecode: Option<Bytes>,
+}
+
+impl Artifact {
+	/// Loads artifact from json.
+	#[cfg(feature = "full-serde")]
+	pub fn load<T: io::Read>(reader: T) -> errors::Result<Self> {
+		serde_json::from_reader(reader).map_err(From::from)
+	}
+}
+
+/// Bytecode is either stored as a hex string (Hardhat, Truffle) or as an
+/// object holding the hex string next to source maps and link references
+/// (Foundry).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BytecodeRepr {
+	Hex(String),
+	Object { object: String },
+}
+
+/// Bytecode which can't be decoded is skipped rather than failing, so that the
+/// abi of the artifact can still be loaded.
+fn deserialize_bytecode<'de, D>(deserializer: D) -> Result<Option<Bytes>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let hex = match Option::<BytecodeRepr>::deserialize(deserializer)? {
+		Some(BytecodeRepr::Hex(hex) | BytecodeRepr::Object { object: hex }) => hex,
+		None => return Ok(None),
+	};
+	let hex = hex.strip_prefix("0x").unwrap_or(&hex);
+	if hex.is_empty() {
+		return Ok(None);
+	}
+	Ok(hex::decode(hex).ok())
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use crate::{Artifact, Contract};
+
+	const ABI: &str = r#"[{ "type": "constructor", "inputs": [{ "name": "a", "type": "uint256" }] }]"#;
+
+	#[test]
+	fn hardhat_artifact() {
+		let json =
+			format!(r#"{{ "contractName": "Foo", "abi": {ABI}, "bytecode": "0x6080", "deployedBytecode": "0x6000" }}"#);
+
+		let artifact: Artifact = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(artifact.abi, serde_json::from_str::<Contract>(ABI).unwrap());
+		assert_eq!(artifact.bytecode, Some(hex!("6080").to_vec()));
+		assert_eq!(artifact.deployed_bytecode, Some(hex!("6000").to_vec()));
+	}
+
+	#[test]
+	fn foundry_artifact() {
+		let json = format!(
+			r#"{{
+				"abi": {ABI},
+				"bytecode": {{ "object": "0x6080", "sourceMap": "", "linkReferences": {{}} }},
+				"deployedBytecode": {{ "object": "0x", "sourceMap": "" }}
+			}}"#
+		);
+
+		let artifact: Artifact = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(artifact.bytecode, Some(hex!("6080").to_vec()));
+		assert_eq!(artifact.deployed_bytecode, None);
+	}
+
+	#[test]
+	fn artifact_without_bytecode() {
+		let artifact: Artifact = serde_json::from_str(&format!(r#"{{ "abi": {ABI} }}"#)).unwrap();
+
+		assert_eq!(artifact.bytecode, None);
+		assert_eq!(artifact.deployed_bytecode, None);
+	}
+
+	#[test]
+	fn artifact_with_undecodable_bytecode() {
+		let placeholder = "0x6080__$1234567890abcdef1234567890abcdef12$__6000";
+		let json = format!(r#"{{ "abi": {ABI}, "bytecode": "{placeholder}", "deployedBytecode": null }}"#);
+
+		let artifact: Artifact = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(artifact.abi, serde_json::from_str::<Contract>(ABI).unwrap());
+		assert_eq!(artifact.bytecode, None);
+		assert_eq!(artifact.deployed_bytecode, None);
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! EIP-55 mixed-case checksum address encoding.
+
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::Address;
+
+/// Returns the `0x` prefixed, EIP-55 checksummed hex representation of an address.
+///
+/// ```
+/// use ethabi::{checksum_address, Address};
+///
+/// let address: Address = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+/// assert_eq!(checksum_address(&address), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// ```
+pub fn checksum_address(address: &Address) -> String {
+	let lower = hex::encode(address);
+	let hash = Keccak256::digest(lower.as_bytes());
+	// each hex digit is uppercased if the corresponding nibble of the hash is 8 or more
+	let nibbles = hash.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]);
+	let checksummed: String =
+		lower.chars().zip(nibbles).map(|(c, nibble)| if nibble >= 8 { c.to_ascii_uppercase() } else { c }).collect();
+	format!("0x{checksummed}")
+}
+
+/// Returns whether the hex representation of an address, with or without `0x`
+/// prefix, has a valid EIP-55 checksum.
+///
+/// All lowercase and all uppercase representations carry no checksum and are
+/// always considered valid, as are strings which aren't addresses at all.
+pub fn has_valid_checksum(address: &str) -> bool {
+	let hex = address.strip_prefix("0x").unwrap_or(address);
+	let is_mixed_case = hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+	if !is_mixed_case || hex.len() != 40 {
+		return true;
+	}
+	match hex::decode(hex) {
+		Ok(bytes) => checksum_address(&Address::from_slice(&bytes))[2..] == *hex,
+		Err(_) => true,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{checksum_address, has_valid_checksum};
+	use crate::Address;
+
+	// test vectors from EIP-55
+	const ADDRESSES: [&str; 4] = [
+		"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+		"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+		"0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+		"0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+	];
+
+	#[test]
+	fn checksum() {
+		for expected in ADDRESSES {
+			let address: Address = expected.to_lowercase().parse().unwrap();
+			assert_eq!(checksum_address(&address), expected);
+		}
+	}
+
+	#[test]
+	fn valid_checksum() {
+		for address in ADDRESSES {
+			assert!(has_valid_checksum(address));
+			assert!(has_valid_checksum(&address[2..]));
+			assert!(has_valid_checksum(&address.to_lowercase()));
+			assert!(has_valid_checksum(&address[2..].to_uppercase()));
+		}
+		assert!(!has_valid_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"));
+		assert!(!has_valid_checksum("5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+	}
+}
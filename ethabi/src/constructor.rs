@@ -13,14 +13,43 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{encode, Bytes, Error, Param, ParamType, Result, Token};
+use crate::{decode, encode, Bytes, Error, Param, ParamType, Result, StateMutability, Token};
 
 /// Contract constructor specification.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "ConstructorRepr"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Constructor {
 	/// Constructor input.
 	pub inputs: Vec<Param>,
+	/// Whether the constructor accepts Ether, either `payable` or `nonpayable`.
+	#[cfg_attr(feature = "serde", serde(rename = "stateMutability"))]
+	pub state_mutability: StateMutability,
+}
+
+/// Constructor as found in abi files, including the `payable` attribute of
+/// ABIs generated before Solidity 0.5.0.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct ConstructorRepr {
+	inputs: Vec<Param>,
+	#[serde(default)]
+	payable: bool,
+	#[serde(rename = "stateMutability")]
+	state_mutability: Option<StateMutability>,
+}
+
+#[cfg(feature = "serde")]
+impl From<ConstructorRepr> for Constructor {
+	fn from(repr: ConstructorRepr) -> Self {
+		// `stateMutability` takes precedence over the legacy attribute.
+		let state_mutability = repr.state_mutability.unwrap_or(match repr.payable {
+			true => StateMutability::Payable,
+			false => StateMutability::NonPayable,
+		});
+
+		Constructor { inputs: repr.inputs, state_mutability }
+	}
 }
 
 impl Constructor {
@@ -29,6 +58,11 @@ impl Constructor {
 		self.inputs.iter().map(|p| p.kind.clone()).collect()
 	}
 
+	/// Returns true if the constructor accepts Ether on deployment.
+	pub fn is_payable(&self) -> bool {
+		self.state_mutability == StateMutability::Payable
+	}
+
 	/// Prepares ABI constructor call with given input params.
 	pub fn encode_input(&self, code: Bytes, tokens: &[Token]) -> Result<Bytes> {
 		let params = self.param_types();
@@ -39,4 +73,62 @@ impl Constructor {
 			Err(Error::InvalidData)
 		}
 	}
+
+	/// Parses the ABI encoded constructor arguments to a list of tokens.
+	///
+	/// `data` must contain only the ABI encoded arguments that were appended
+	/// to the creation bytecode, so the caller has to strip the code first.
+	pub fn decode_input(&self, data: &[u8]) -> Result<Vec<Token>> {
+		decode(&self.param_types(), data)
+	}
+
+	/// Returns the JSON fragment describing this constructor in a contract ABI.
+	#[cfg(feature = "full-serde")]
+	pub fn to_json_value(&self) -> serde_json::Value {
+		crate::operation::OperationRef::Constructor(self).to_json_value()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{Constructor, Param, ParamType, StateMutability, Token};
+
+	#[test]
+	fn test_constructor_decode_input() {
+		let constructor = Constructor {
+			inputs: vec![
+				Param { name: "a".to_owned(), kind: ParamType::Address, internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::String, internal_type: None, components: vec![] },
+			],
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		let code = hex!("6080604052").to_vec();
+		let tokens = vec![Token::Address([0x11u8; 20].into()), Token::String("gavofyork".to_owned())];
+		let encoded = constructor.encode_input(code.clone(), &tokens).unwrap();
+
+		assert_eq!(constructor.decode_input(&encoded[code.len()..]).unwrap(), tokens);
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_constructor_state_mutability() {
+		let state_mutability = |json: &str| serde_json::from_str::<Constructor>(json).unwrap().state_mutability;
+
+		assert_eq!(state_mutability(r#"{ "inputs": [] }"#), StateMutability::NonPayable);
+		assert_eq!(state_mutability(r#"{ "inputs": [], "payable": true }"#), StateMutability::Payable);
+		assert_eq!(state_mutability(r#"{ "inputs": [], "stateMutability": "payable" }"#), StateMutability::Payable);
+		assert_eq!(
+			state_mutability(r#"{ "inputs": [], "payable": true, "stateMutability": "nonpayable" }"#),
+			StateMutability::NonPayable
+		);
+
+		let constructor = Constructor { inputs: vec![], state_mutability: StateMutability::Payable };
+		assert!(constructor.is_payable());
+		assert_eq!(serde_json::to_string(&constructor).unwrap(), r#"{"inputs":[],"stateMutability":"payable"}"#);
+	}
 }
@@ -15,16 +15,25 @@ use std::io;
 
 #[cfg(feature = "serde")]
 use serde::{
-	de::{SeqAccess, Visitor},
+	de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor},
 	ser::SerializeSeq,
 	Deserialize, Deserializer, Serialize, Serializer,
 };
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
+use crate::{
+	error::Error as AbiError,
+	errors,
+	no_std_prelude::Cow,
+	signature::{canonical_signature, long_signature},
+	split_selector, Constructor, Error, Event, Function, Hash, Token,
+};
 #[cfg(feature = "serde")]
-use crate::operation::Operation;
-use crate::{error::Error as AbiError, errors, Constructor, Error, Event, Function};
+use crate::{
+	operation::{Operation, OperationRef},
+	StateMutability,
+};
 
 /// API building calls to contracts ABI.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -41,6 +50,8 @@ pub struct Contract {
 	pub receive: bool,
 	/// Contract has fallback function.
 	pub fallback: bool,
+	/// Contract has a fallback function with `payable` state mutability.
+	pub payable_fallback: bool,
 }
 
 #[cfg(feature = "serde")]
@@ -83,8 +94,9 @@ impl<'a> Visitor<'a> for ContractVisitor {
 				Operation::Error(error) => {
 					result.errors.entry(error.name.clone()).or_default().push(error);
 				}
-				Operation::Fallback => {
+				Operation::Fallback { state_mutability } => {
 					result.fallback = true;
+					result.payable_fallback |= state_mutability == StateMutability::Payable;
 				}
 				Operation::Receive => {
 					result.receive = true;
@@ -94,6 +106,27 @@ impl<'a> Visitor<'a> for ContractVisitor {
 
 		Ok(result)
 	}
+
+	/// Build artifacts of Hardhat, Truffle or Foundry wrap the abi in an object
+	/// next to the bytecode and other metadata.
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'a>,
+	{
+		let mut abi = None;
+		while let Some(key) = map.next_key::<String>()? {
+			if key == "abi" {
+				if abi.is_some() {
+					return Err(de::Error::duplicate_field("abi"));
+				}
+				abi = Some(map.next_value::<Contract>()?);
+			} else {
+				map.next_value::<IgnoredAny>()?;
+			}
+		}
+
+		abi.ok_or_else(|| de::Error::missing_field("abi"))
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -102,29 +135,6 @@ impl Serialize for Contract {
 	where
 		S: Serializer,
 	{
-		// Serde's FlatMapSerializer is private, so we'll have to improvise...
-		#[derive(Serialize)]
-		#[serde(tag = "type")]
-		enum OperationRef<'a> {
-			#[serde(rename = "constructor")]
-			Constructor(&'a Constructor),
-
-			#[serde(rename = "function")]
-			Function(&'a Function),
-
-			#[serde(rename = "event")]
-			Event(&'a Event),
-
-			#[serde(rename = "error")]
-			Error(&'a AbiError),
-
-			#[serde(rename = "fallback")]
-			Fallback,
-
-			#[serde(rename = "receive")]
-			Receive,
-		}
-
 		let mut seq = serializer.serialize_seq(None)?;
 
 		if let Some(constructor) = &self.constructor {
@@ -154,7 +164,9 @@ impl Serialize for Contract {
 		}
 
 		if self.fallback {
-			seq.serialize_element(&OperationRef::Fallback)?;
+			let state_mutability =
+				if self.payable_fallback { StateMutability::Payable } else { StateMutability::NonPayable };
+			seq.serialize_element(&OperationRef::Fallback { state_mutability })?;
 		}
 
 		seq.end()
@@ -162,7 +174,15 @@ impl Serialize for Contract {
 }
 
 impl Contract {
+	/// Returns a builder for assembling a contract from individual entries.
+	pub fn builder() -> ContractBuilder {
+		ContractBuilder::default()
+	}
+
 	/// Loads contract from json.
+	///
+	/// Accepts both a bare abi array and a build artifact object holding the
+	/// abi array in its `abi` field.
 	#[cfg(feature = "full-serde")]
 	pub fn load<T: io::Read>(reader: T) -> errors::Result<Self> {
 		serde_json::from_reader(reader).map_err(From::from)
@@ -204,6 +224,174 @@ impl Contract {
 		self.errors.get(name).ok_or_else(|| Error::InvalidName(name.to_owned()))
 	}
 
+	/// Returns all function selectors which are shared by more than one
+	/// function, together with the colliding functions, ordered by selector.
+	pub fn function_selector_collisions(&self) -> Vec<([u8; 4], Vec<&Function>)> {
+		let mut by_selector = BTreeMap::<[u8; 4], Vec<&Function>>::new();
+		for function in self.functions() {
+			by_selector.entry(function.short_signature()).or_default().push(function);
+		}
+
+		by_selector.into_iter().filter(|(_, functions)| functions.len() > 1).collect()
+	}
+
+	/// Adds the functions, events and errors of `other` to this contract.
+	///
+	/// This allows combining ABIs which are split across multiple fragments, such as the facets
+	/// of an EIP-2535 diamond. Entries with the same signature as an existing entry are skipped.
+	/// If a function or error of `other` has the selector of an entry with a different signature,
+	/// an error is returned and the contract is left unchanged. Collisions already present in this
+	/// contract are not reported.
+	pub fn merge(&mut self, other: Contract) -> errors::Result<()> {
+		fn check_selectors<'a>(
+			existing: impl Iterator<Item = (&'a str, [u8; 4], Hash)>,
+			added: impl Iterator<Item = (&'a str, [u8; 4], Hash)>,
+		) -> errors::Result<()> {
+			let mut by_selector = BTreeMap::<[u8; 4], Vec<(&str, Hash)>>::new();
+			for (name, selector, signature) in existing {
+				by_selector.entry(selector).or_default().push((name, signature));
+			}
+			for (name, selector, signature) in added {
+				let entries = by_selector.entry(selector).or_default();
+				if entries.iter().any(|(_, existing)| *existing == signature) {
+					continue;
+				}
+				if let Some((other_name, _)) = entries.first() {
+					return Err(Error::Other(Cow::Owned(format!(
+						"selector 0x{} of `{name}` collides with `{other_name}`",
+						hex::encode(selector)
+					))));
+				}
+				entries.push((name, signature));
+			}
+			Ok(())
+		}
+
+		fn function_entry(function: &Function) -> (&str, [u8; 4], Hash) {
+			(&function.name, function.short_signature(), long_signature(&function.name, &function.input_param_types()))
+		}
+		fn error_entry(error: &AbiError) -> (&str, [u8; 4], Hash) {
+			(&error.name, error.selector(), error.signature())
+		}
+
+		let function_signature = |function: &Function| long_signature(&function.name, &function.input_param_types());
+		check_selectors(self.functions().map(function_entry), other.functions().map(function_entry))?;
+		check_selectors(self.errors().map(error_entry), other.errors().map(error_entry))?;
+
+		if let Some(constructor) = other.constructor {
+			match self.constructor {
+				Some(ref existing) if *existing != constructor => {
+					return Err(Error::Other(Cow::Borrowed("contracts have different constructors")));
+				}
+				_ => self.constructor = Some(constructor),
+			}
+		}
+		for function in other.functions.into_values().flatten() {
+			let functions = self.functions.entry(function.name.clone()).or_default();
+			if !functions.iter().any(|existing| function_signature(existing) == function_signature(&function)) {
+				functions.push(function);
+			}
+		}
+		for event in other.events.into_values().flatten() {
+			let events = self.events.entry(event.name.clone()).or_default();
+			if !events.contains(&event) {
+				events.push(event);
+			}
+		}
+		for error in other.errors.into_values().flatten() {
+			let errors = self.errors.entry(error.name.clone()).or_default();
+			if !errors.iter().any(|existing| existing.signature() == error.signature()) {
+				errors.push(error);
+			}
+		}
+		self.receive |= other.receive;
+		self.fallback |= other.fallback;
+		self.payable_fallback |= other.payable_fallback;
+
+		Ok(())
+	}
+
+	/// Returns true if plain ether transfers to the contract succeed, that is
+	/// if it has a receive function or a payable fallback function.
+	pub fn accepts_ether(&self) -> bool {
+		self.receive || (self.fallback && self.payable_fallback)
+	}
+
+	/// Iterate over the selectors of all functions of the contract in arbitrary order.
+	pub fn selectors(&self) -> impl Iterator<Item = ([u8; 4], &Function)> {
+		self.functions().map(|function| (function.short_signature(), function))
+	}
+
+	/// Returns the custom error with the given 4 byte selector.
+	pub fn error_by_selector(&self, selector: [u8; 4]) -> Option<&AbiError> {
+		self.errors().find(|error| error.selector() == selector)
+	}
+
+	/// Decodes revert data returned by a call into the custom error it encodes
+	/// and its parameters.
+	///
+	/// `data` must start with the 4 byte selector of one of the contract's errors.
+	pub fn decode_error(&self, data: &[u8]) -> errors::Result<(&AbiError, Vec<Token>)> {
+		let (selector, data) = split_selector(data, "revert data")?;
+		let error = self.error_by_selector(selector).ok_or_else(|| {
+			Error::Other(Cow::Owned(format!("no error with selector 0x{} in contract", hex::encode(selector))))
+		})?;
+		Ok((error, error.decode(data)?))
+	}
+
+	/// Iterate over the topics of all non anonymous events of the contract in
+	/// arbitrary order. Anonymous events are skipped, as their logs don't start
+	/// with a signature topic.
+	pub fn event_topics(&self) -> impl Iterator<Item = (Hash, &Event)> {
+		self.events().filter(|event| !event.anonymous).map(|event| (event.signature(), event))
+	}
+
+	/// Returns the non anonymous event whose signature is the given first topic of a log.
+	///
+	/// Overloaded events have distinct signatures, so the returned event is the one
+	/// matching the param types of the log, which can be decoded with [`Event::parse_log`].
+	pub fn event_by_topic0(&self, topic0: Hash) -> Option<&Event> {
+		self.event_topics().find(|(topic, _)| *topic == topic0).map(|(_, event)| event)
+	}
+
+	/// Computes the selectors of all functions and errors and the signatures of
+	/// all events once, for repeated lookups without hashing.
+	pub fn prepare(&self) -> PreparedContract<'_> {
+		let mut functions = BTreeMap::new();
+		for (selector, function) in self.selectors() {
+			functions.entry(selector).or_insert(function);
+		}
+		let mut events = BTreeMap::new();
+		for (topic, event) in self.event_topics() {
+			events.entry(topic).or_insert(event);
+		}
+		let mut errors = BTreeMap::new();
+		for error in self.errors() {
+			errors.entry(error.selector()).or_insert(error);
+		}
+		PreparedContract { contract: self, functions, events, errors }
+	}
+
+	/// Compares the functions, events and errors of this contract with those of
+	/// `other`, which is treated as the newer version.
+	///
+	/// Functions and errors are matched by selector and events by signature, so
+	/// the order of entries in the ABI doesn't matter. An entry whose selector
+	/// or signature changed, e.g. by changing a param type, is reported as
+	/// removed and added. Entries with the same selector or signature which
+	/// differ otherwise, e.g. in param names, outputs or state mutability, are
+	/// reported as changed.
+	pub fn diff<'a>(&'a self, other: &'a Contract) -> ContractDiff<'a> {
+		let error_selectors = |contract: &'a Contract| contract.errors().map(|error| (error.selector(), error));
+		let event_signatures = |contract: &'a Contract| contract.events().map(|event| (event.signature(), event));
+
+		ContractDiff {
+			functions: EntryDiff::new(self.selectors(), other.selectors()),
+			events: EntryDiff::new(event_signatures(self), event_signatures(other)),
+			errors: EntryDiff::new(error_selectors(self), error_selectors(other)),
+		}
+	}
+
 	/// Iterate over all functions of the contract in arbitrary order.
 	pub fn functions(&self) -> Functions {
 		Functions(self.functions.values().flatten())
@@ -218,6 +406,201 @@ impl Contract {
 	pub fn errors(&self) -> AbiErrors {
 		AbiErrors(self.errors.values().flatten())
 	}
+
+	/// Returns all functions of the contract sorted by their canonical signature.
+	///
+	/// Unlike [`Contract::functions`], the order doesn't depend on how the ABI was
+	/// laid out, which makes it suitable for generating code deterministically.
+	pub fn functions_sorted(&self) -> Vec<&Function> {
+		let mut functions: Vec<_> = self.functions().collect();
+		functions.sort_by_cached_key(|function| function.canonical_signature());
+		functions
+	}
+
+	/// Returns all events of the contract sorted by their canonical signature.
+	pub fn events_sorted(&self) -> Vec<&Event> {
+		let mut events: Vec<_> = self.events().collect();
+		events.sort_by_cached_key(|event| event.signature_string());
+		events
+	}
+
+	/// Returns all errors of the contract sorted by their canonical signature.
+	pub fn errors_sorted(&self) -> Vec<&AbiError> {
+		let mut errors: Vec<_> = self.errors().collect();
+		errors
+			.sort_by_cached_key(|error| canonical_signature(&error.name, error.inputs.iter().map(|param| &param.kind)));
+		errors
+	}
+
+	/// Returns the canonical signatures of all functions of the contract, such
+	/// as `transfer(address,uint256)`, in sorted order.
+	pub fn function_signatures(&self) -> Vec<String> {
+		self.functions_sorted().into_iter().map(Function::canonical_signature).collect()
+	}
+
+	/// Returns the canonical signatures of all events of the contract, such as
+	/// `Transfer(address,address,uint256)`, in sorted order.
+	pub fn event_signatures(&self) -> Vec<String> {
+		self.events_sorted().into_iter().map(Event::signature_string).collect()
+	}
+
+	/// Returns the canonical signatures of all errors of the contract, such as
+	/// `InsufficientBalance(uint256,uint256)`, in sorted order.
+	pub fn error_signatures(&self) -> Vec<String> {
+		self.errors_sorted()
+			.into_iter()
+			.map(|error| canonical_signature(&error.name, error.inputs.iter().map(|param| &param.kind)))
+			.collect()
+	}
+}
+
+/// A contract with precomputed function and error selectors and event
+/// signatures, created by [`Contract::prepare`].
+///
+/// Lookups agree with [`Contract::selectors`], [`Contract::error_by_selector`]
+/// and [`Contract::event_by_topic0`], but don't compute any hashes. If several
+/// entries share a selector, the one iterated first by the contract is returned.
+#[derive(Clone, Debug)]
+pub struct PreparedContract<'a> {
+	contract: &'a Contract,
+	functions: BTreeMap<[u8; 4], &'a Function>,
+	events: BTreeMap<Hash, &'a Event>,
+	errors: BTreeMap<[u8; 4], &'a AbiError>,
+}
+
+impl<'a> PreparedContract<'a> {
+	/// Returns the contract this was prepared from.
+	pub fn contract(&self) -> &'a Contract {
+		self.contract
+	}
+
+	/// Returns the function with the given 4 byte selector.
+	pub fn function_by_selector(&self, selector: [u8; 4]) -> Option<&'a Function> {
+		self.functions.get(&selector).copied()
+	}
+
+	/// Returns the non anonymous event whose signature is the given first topic of a log.
+	pub fn event_by_topic0(&self, topic0: Hash) -> Option<&'a Event> {
+		self.events.get(&topic0).copied()
+	}
+
+	/// Returns the custom error with the given 4 byte selector.
+	pub fn error_by_selector(&self, selector: [u8; 4]) -> Option<&'a AbiError> {
+		self.errors.get(&selector).copied()
+	}
+}
+
+/// Differences between two versions of a contract, created by [`Contract::diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractDiff<'a> {
+	/// Differences in functions, matched by selector.
+	pub functions: EntryDiff<'a, Function>,
+	/// Differences in events, matched by signature.
+	pub events: EntryDiff<'a, Event>,
+	/// Differences in errors, matched by selector.
+	pub errors: EntryDiff<'a, AbiError>,
+}
+
+impl ContractDiff<'_> {
+	/// Returns whether the functions, events and errors of both contracts are the same.
+	pub fn is_empty(&self) -> bool {
+		self.functions.is_empty() && self.events.is_empty() && self.errors.is_empty()
+	}
+}
+
+/// Differences in one kind of contract entry, ordered by selector or signature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryDiff<'a, T> {
+	/// Entries only in the newer contract.
+	pub added: Vec<&'a T>,
+	/// Entries only in the older contract.
+	pub removed: Vec<&'a T>,
+	/// Entries in both contracts which differ, as old and new entry.
+	pub changed: Vec<(&'a T, &'a T)>,
+}
+
+impl<'a, T: PartialEq> EntryDiff<'a, T> {
+	fn new<K: Ord>(old: impl Iterator<Item = (K, &'a T)>, new: impl Iterator<Item = (K, &'a T)>) -> Self {
+		let mut old_entries = BTreeMap::new();
+		for (key, entry) in old {
+			old_entries.entry(key).or_insert(entry);
+		}
+		let mut new_entries = BTreeMap::new();
+		for (key, entry) in new {
+			new_entries.entry(key).or_insert(entry);
+		}
+
+		let mut diff = EntryDiff { added: vec![], removed: vec![], changed: vec![] };
+		for (key, old_entry) in &old_entries {
+			match new_entries.get(key) {
+				None => diff.removed.push(*old_entry),
+				Some(new_entry) if old_entry != new_entry => diff.changed.push((*old_entry, *new_entry)),
+				Some(_) => {}
+			}
+		}
+		diff.added =
+			new_entries.into_iter().filter(|(key, _)| !old_entries.contains_key(key)).map(|(_, entry)| entry).collect();
+		diff
+	}
+
+	/// Returns whether no entries were added, removed or changed.
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+	}
+}
+
+/// Builder for a [`Contract`], created by [`Contract::builder`].
+///
+/// Entries sharing a name are kept as overloads, in the order they are added.
+///
+/// ```
+/// use ethabi::{Contract, Function, Param, ParamType, StateMutability};
+///
+/// # #[allow(deprecated)]
+/// let transfer = Function {
+///     name: "transfer".to_owned(),
+///     inputs: vec![Param::new("to", ParamType::Address), Param::new("amount", ParamType::Uint(256))],
+///     outputs: vec![Param::new("", ParamType::Bool)],
+///     constant: None,
+///     state_mutability: StateMutability::NonPayable,
+/// };
+/// let contract = Contract::builder().function(transfer).build();
+/// assert!(contract.function("transfer").is_ok());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ContractBuilder {
+	contract: Contract,
+}
+
+impl ContractBuilder {
+	/// Sets the contract constructor, replacing any previously set one.
+	pub fn constructor(mut self, constructor: Constructor) -> Self {
+		self.contract.constructor = Some(constructor);
+		self
+	}
+
+	/// Adds a function, as an overload if a function with the same name exists.
+	pub fn function(mut self, function: Function) -> Self {
+		self.contract.functions.entry(function.name.clone()).or_default().push(function);
+		self
+	}
+
+	/// Adds an event, as an overload if an event with the same name exists.
+	pub fn event(mut self, event: Event) -> Self {
+		self.contract.events.entry(event.name.clone()).or_default().push(event);
+		self
+	}
+
+	/// Adds an error, as an overload if an error with the same name exists.
+	pub fn error(mut self, error: AbiError) -> Self {
+		self.contract.errors.entry(error.name.clone()).or_default().push(error);
+		self
+	}
+
+	/// Returns the assembled contract.
+	pub fn build(self) -> Contract {
+		self.contract
+	}
 }
 
 /// Contract functions iterator.
@@ -261,7 +644,12 @@ mod test {
 	use alloc::collections::BTreeMap;
 	use core::iter::FromIterator;
 
-	use crate::{tests::assert_ser_de, AbiError, Constructor, Contract, Event, EventParam, Function, Param, ParamType};
+	use hex_literal::hex;
+
+	use crate::{
+		tests::assert_ser_de, AbiError, Constructor, Contract, Event, EventParam, Function, Hash, Param, ParamType,
+		StateMutability, Token,
+	};
 
 	#[test]
 	fn empty() {
@@ -278,6 +666,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -306,13 +695,20 @@ mod test {
 			deserialized,
 			Contract {
 				constructor: Some(Constructor {
-					inputs: vec![Param { name: "a".to_string(), kind: ParamType::Address, internal_type: None }]
+					inputs: vec![Param {
+						name: "a".to_string(),
+						kind: ParamType::Address,
+						internal_type: None,
+						components: vec![]
+					}],
+					state_mutability: StateMutability::NonPayable,
 				}),
 				functions: BTreeMap::new(),
 				events: BTreeMap::new(),
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -363,11 +759,13 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							outputs: vec![Param {
 								name: "res".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							constant: None,
 							state_mutability: Default::default(),
@@ -388,6 +786,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -438,11 +837,13 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							outputs: vec![Param {
 								name: "res".to_string(),
 								kind: ParamType::Address,
 								internal_type: None,
+								components: vec![],
 							}],
 							constant: None,
 							state_mutability: Default::default(),
@@ -460,6 +861,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -512,6 +914,7 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								indexed: false,
+								components: vec![],
 							}],
 							anonymous: false,
 						}]
@@ -520,7 +923,12 @@ mod test {
 						"bar".to_string(),
 						vec![Event {
 							name: "bar".to_string(),
-							inputs: vec![EventParam { name: "a".to_string(), kind: ParamType::Address, indexed: true }],
+							inputs: vec![EventParam {
+								name: "a".to_string(),
+								kind: ParamType::Address,
+								indexed: true,
+								components: vec![]
+							}],
 							anonymous: false,
 						}]
 					),
@@ -528,6 +936,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -580,12 +989,18 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Address,
 								indexed: false,
+								components: vec![],
 							}],
 							anonymous: false,
 						},
 						Event {
 							name: "foo".to_string(),
-							inputs: vec![EventParam { name: "a".to_string(), kind: ParamType::Address, indexed: true }],
+							inputs: vec![EventParam {
+								name: "a".to_string(),
+								kind: ParamType::Address,
+								indexed: true,
+								components: vec![]
+							}],
 							anonymous: false,
 						},
 					]
@@ -593,6 +1008,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -652,8 +1068,14 @@ mod test {
 									name: "available".to_string(),
 									kind: ParamType::Uint(256),
 									internal_type: None,
+									components: vec![],
 								},
-								Param { name: "required".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "required".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						}]
 					),
@@ -662,14 +1084,25 @@ mod test {
 						vec![AbiError {
 							name: "bar".to_string(),
 							inputs: vec![
-								Param { name: "a".to_string(), kind: ParamType::Uint(256), internal_type: None },
-								Param { name: "b".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "a".to_string(),
+									kind: ParamType::Uint(256),
+									internal_type: None,
+									components: vec![]
+								},
+								Param {
+									name: "b".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						}]
 					),
 				]),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -724,25 +1157,184 @@ mod test {
 								name: "a".to_string(),
 								kind: ParamType::Uint(256),
 								internal_type: None,
+								components: vec![],
 							}],
 						},
 						AbiError {
 							name: "foo".to_string(),
 							inputs: vec![
-								Param { name: "a".to_string(), kind: ParamType::Uint(256), internal_type: None },
-								Param { name: "b".to_string(), kind: ParamType::Address, internal_type: None }
+								Param {
+									name: "a".to_string(),
+									kind: ParamType::Uint(256),
+									internal_type: None,
+									components: vec![]
+								},
+								Param {
+									name: "b".to_string(),
+									kind: ParamType::Address,
+									internal_type: None,
+									components: vec![]
+								}
 							],
 						},
 					]
 				),]),
 				receive: false,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
 		assert_ser_de(&deserialized);
 	}
 
+	#[test]
+	fn function_selector_collisions() {
+		let json = r#"
+			[
+				{
+					"type": "function",
+					"name": "burn",
+					"inputs": [{ "name": "a", "type": "uint256" }],
+					"outputs": []
+				},
+				{
+					"type": "function",
+					"name": "collate_propagate_storage",
+					"inputs": [{ "name": "a", "type": "bytes16" }],
+					"outputs": []
+				},
+				{
+					"type": "function",
+					"name": "foo",
+					"inputs": [],
+					"outputs": []
+				}
+			]
+		"#;
+
+		let contract: Contract = serde_json::from_str(json).unwrap();
+		let collisions = contract.function_selector_collisions();
+
+		assert_eq!(collisions.len(), 1);
+		let (selector, functions) = &collisions[0];
+		assert_eq!(selector, &[0x42, 0x96, 0x6c, 0x68]);
+		assert_eq!(
+			functions.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+			vec!["burn", "collate_propagate_storage"]
+		);
+	}
+
+	#[test]
+	fn selectors_and_event_topics() {
+		let json = r#"
+			[
+				{ "type": "function", "name": "transfer", "inputs": [{ "name": "to", "type": "address" }, { "name": "value", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "approve", "inputs": [{ "name": "spender", "type": "address" }, { "name": "value", "type": "uint256" }], "outputs": [] },
+				{ "type": "event", "name": "Transfer", "inputs": [{ "name": "from", "type": "address", "indexed": true }, { "name": "to", "type": "address", "indexed": true }, { "name": "value", "type": "uint256", "indexed": false }], "anonymous": false },
+				{ "type": "event", "name": "Anonymous", "inputs": [], "anonymous": true }
+			]
+		"#;
+
+		let contract: Contract = serde_json::from_str(json).unwrap();
+
+		let selectors: BTreeMap<[u8; 4], &str> =
+			contract.selectors().map(|(selector, function)| (selector, function.name.as_str())).collect();
+		assert_eq!(selectors, BTreeMap::from_iter(vec![(hex!("a9059cbb"), "transfer"), (hex!("095ea7b3"), "approve")]));
+
+		let topics: Vec<_> = contract.event_topics().map(|(topic, event)| (topic, event.name.as_str())).collect();
+		assert_eq!(
+			topics,
+			vec![(Hash::from(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")), "Transfer")]
+		);
+	}
+
+	#[test]
+	fn merge() {
+		let facet_a = r#"
+			[
+				{ "type": "constructor", "inputs": [] },
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "supportsInterface", "inputs": [{ "name": "id", "type": "bytes4" }], "outputs": [{ "name": "", "type": "bool" }] },
+				{ "type": "event", "name": "Foo", "inputs": [], "anonymous": false },
+				{ "type": "error", "name": "Unauthorized", "inputs": [] }
+			]
+		"#;
+		let facet_b = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "bool" }], "outputs": [] },
+				{ "type": "function", "name": "supportsInterface", "inputs": [{ "name": "id", "type": "bytes4" }], "outputs": [{ "name": "", "type": "bool" }] },
+				{ "type": "event", "name": "Foo", "inputs": [], "anonymous": false },
+				{ "type": "error", "name": "Unauthorized", "inputs": [] },
+				{ "type": "receive" }
+			]
+		"#;
+
+		let mut contract: Contract = serde_json::from_str(facet_a).unwrap();
+		contract.merge(serde_json::from_str(facet_b).unwrap()).unwrap();
+
+		assert!(contract.constructor.is_some());
+		assert_eq!(contract.functions_by_name("foo").unwrap().len(), 2);
+		assert_eq!(contract.functions_by_name("supportsInterface").unwrap().len(), 1);
+		assert_eq!(contract.events_by_name("Foo").unwrap().len(), 1);
+		assert_eq!(contract.errors_by_name("Unauthorized").unwrap().len(), 1);
+		assert!(contract.receive);
+		assert!(!contract.fallback);
+	}
+
+	#[test]
+	fn merge_selector_collision() {
+		let facet_a = r#"
+			[
+				{ "type": "function", "name": "burn", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] }
+			]
+		"#;
+		let facet_b = r#"
+			[
+				{ "type": "function", "name": "collate_propagate_storage", "inputs": [{ "name": "a", "type": "bytes16" }], "outputs": [] },
+				{ "type": "function", "name": "foo", "inputs": [], "outputs": [] }
+			]
+		"#;
+
+		let mut contract: Contract = serde_json::from_str(facet_a).unwrap();
+		let unchanged = contract.clone();
+		let err = contract.merge(serde_json::from_str(facet_b).unwrap()).unwrap_err();
+
+		assert!(matches!(
+			err,
+			crate::Error::Other(msg) if msg == "selector 0x42966c68 of `collate_propagate_storage` collides with `burn`"
+		));
+		assert_eq!(contract, unchanged);
+	}
+
+	#[test]
+	fn merge_into_contract_with_collision() {
+		let abi = r#"
+			[
+				{ "type": "function", "name": "burn", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "collate_propagate_storage", "inputs": [{ "name": "a", "type": "bytes16" }], "outputs": [] }
+			]
+		"#;
+		let facet = r#"
+			[
+				{ "type": "function", "name": "burn", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "foo", "inputs": [], "outputs": [] }
+			]
+		"#;
+
+		// merging an empty contract is a no-op
+		let mut contract: Contract = serde_json::from_str(abi).unwrap();
+		let unchanged = contract.clone();
+		contract.merge(Contract::default()).unwrap();
+		assert_eq!(contract, unchanged);
+
+		// the collision already in the contract doesn't prevent merging other functions
+		contract.merge(serde_json::from_str(facet).unwrap()).unwrap();
+		assert_eq!(contract.functions_by_name("burn").unwrap().len(), 1);
+		assert_eq!(contract.functions_by_name("foo").unwrap().len(), 1);
+		assert_eq!(contract.function_selector_collisions().len(), 1);
+	}
+
 	#[test]
 	fn receive() {
 		let json = r#"
@@ -762,6 +1354,7 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: true,
 				fallback: false,
+				payable_fallback: false,
 			}
 		);
 
@@ -787,9 +1380,275 @@ mod test {
 				errors: BTreeMap::new(),
 				receive: false,
 				fallback: true,
+				payable_fallback: false,
 			}
 		);
 
 		assert_ser_de(&deserialized);
 	}
+
+	#[test]
+	fn named_nested_struct_round_trip() {
+		let json = concat!(
+			r#"[{"type":"function","name":"submit","inputs":[{"internalType":"struct Bank.Order[]","name":"orders","#,
+			r#""type":"tuple[]","components":[{"internalType":"address","name":"owner","type":"address"},"#,
+			r#"{"internalType":"struct Bank.Amount","name":"amount","type":"tuple","components":["#,
+			r#"{"internalType":"uint128","name":"value","type":"uint128"},{"name":"unit","type":"uint8"}]}]}],"#,
+			r#""outputs":[],"stateMutability":"nonpayable"},"#,
+			r#"{"type":"event","name":"Submitted","inputs":[{"name":"amount","type":"tuple","indexed":false,"#,
+			r#""components":[{"name":"value","type":"uint128"},{"name":"unit","type":"uint8"}]}],"anonymous":false}]"#
+		);
+
+		let deserialized: Contract = serde_json::from_str(json).unwrap();
+
+		assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+	}
+
+	#[test]
+	fn payable_fallback() {
+		let json = r#"
+			[
+				{ "type": "fallback", "stateMutability": "payable" }
+			]
+		"#;
+
+		let deserialized: Contract = serde_json::from_str(json).unwrap();
+
+		assert!(deserialized.fallback);
+		assert!(deserialized.payable_fallback);
+		assert_ser_de(&deserialized);
+	}
+
+	#[test]
+	fn accepts_ether() {
+		let load = |json: &str| serde_json::from_str::<Contract>(json).unwrap().accepts_ether();
+
+		assert!(!load(r#"[]"#));
+		assert!(load(r#"[{ "type": "receive", "stateMutability": "payable" }]"#));
+		assert!(!load(r#"[{ "type": "fallback" }]"#));
+		assert!(!load(r#"[{ "type": "fallback", "stateMutability": "nonpayable" }]"#));
+		assert!(load(r#"[{ "type": "fallback", "stateMutability": "payable" }]"#));
+	}
+
+	#[test]
+	fn sorted_entries() {
+		let json = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "bar", "inputs": [], "outputs": [] },
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "address" }], "outputs": [] },
+				{ "type": "event", "name": "Transfer", "inputs": [], "anonymous": false },
+				{ "type": "event", "name": "Approval", "inputs": [], "anonymous": false },
+				{ "type": "error", "name": "Oops", "inputs": [{ "name": "a", "type": "bool" }] },
+				{ "type": "error", "name": "Oops", "inputs": [{ "name": "a", "type": "address" }] }
+			]
+		"#;
+
+		let contract: Contract = serde_json::from_str(json).unwrap();
+
+		let functions: Vec<_> = contract.functions_sorted().iter().map(|f| f.canonical_signature()).collect();
+		assert_eq!(functions, vec!["bar()", "foo(address)", "foo(uint256)"]);
+		let events: Vec<_> = contract.events_sorted().iter().map(|e| e.name.as_str()).collect();
+		assert_eq!(events, vec!["Approval", "Transfer"]);
+		let errors: Vec<_> = contract.errors_sorted().iter().map(|e| e.inputs[0].kind.clone()).collect();
+		assert_eq!(errors, vec![ParamType::Address, ParamType::Bool]);
+	}
+
+	#[test]
+	fn signatures() {
+		let json = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "bar", "inputs": [], "outputs": [{ "name": "", "type": "bool" }] },
+				{ "type": "event", "name": "Transfer", "inputs": [
+					{ "name": "from", "type": "address", "indexed": true },
+					{ "name": "value", "type": "uint256", "indexed": false }
+				], "anonymous": false },
+				{ "type": "error", "name": "Oops", "inputs": [{ "name": "a", "type": "bool" }] },
+				{ "type": "error", "name": "Bad", "inputs": [] }
+			]
+		"#;
+
+		let contract: Contract = serde_json::from_str(json).unwrap();
+
+		assert_eq!(contract.function_signatures(), vec!["bar()", "foo(uint256)"]);
+		assert_eq!(contract.event_signatures(), vec!["Transfer(address,uint256)"]);
+		assert_eq!(contract.error_signatures(), vec!["Bad()", "Oops(bool)"]);
+	}
+
+	#[test]
+	fn builder() {
+		let function = |name: &str, kind| Function {
+			name: name.to_owned(),
+			inputs: vec![Param::new("a", kind)],
+			outputs: vec![],
+			constant: None,
+			state_mutability: Default::default(),
+		};
+		let event = Event {
+			name: "Transfer".to_owned(),
+			inputs: vec![EventParam {
+				name: "from".to_owned(),
+				kind: ParamType::Address,
+				indexed: true,
+				components: vec![],
+			}],
+			anonymous: false,
+		};
+		let error = AbiError { name: "Oops".to_owned(), inputs: vec![] };
+		let constructor = Constructor {
+			inputs: vec![Param::new("owner", ParamType::Address)],
+			state_mutability: StateMutability::Payable,
+		};
+
+		let contract = Contract::builder()
+			.constructor(constructor.clone())
+			.function(function("foo", ParamType::Uint(256)))
+			.function(function("bar", ParamType::Bool))
+			.function(function("foo", ParamType::Address))
+			.event(event.clone())
+			.error(error.clone())
+			.build();
+
+		assert_eq!(contract.constructor, Some(constructor));
+		assert_eq!(contract.function_signatures(), vec!["bar(bool)", "foo(address)", "foo(uint256)"]);
+		assert_eq!(contract.functions_by_name("foo").unwrap()[0], function("foo", ParamType::Uint(256)));
+		assert_eq!(contract.event("Transfer").unwrap(), &event);
+		assert_eq!(contract.error("Oops").unwrap(), &error);
+		assert!(!contract.receive && !contract.fallback);
+
+		let json = serde_json::to_string(&contract).unwrap();
+		assert_eq!(serde_json::from_str::<Contract>(&json).unwrap(), contract);
+		assert_eq!(Contract::builder().build(), Contract::default());
+	}
+
+	#[test]
+	fn prepare() {
+		let json = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "address" }], "outputs": [] },
+				{ "type": "event", "name": "Transfer", "inputs": [], "anonymous": false },
+				{ "type": "event", "name": "Hidden", "inputs": [], "anonymous": true },
+				{ "type": "error", "name": "Oops", "inputs": [{ "name": "a", "type": "bool" }] }
+			]
+		"#;
+
+		let contract: Contract = serde_json::from_str(json).unwrap();
+		let prepared = contract.prepare();
+
+		for function in contract.functions() {
+			assert_eq!(prepared.function_by_selector(function.short_signature()), Some(function));
+		}
+		for event in contract.events() {
+			assert_eq!(prepared.event_by_topic0(event.signature()), contract.event_by_topic0(event.signature()));
+		}
+		assert_eq!(prepared.event_by_topic0(contract.event("Hidden").unwrap().signature()), None);
+		let oops = contract.error("Oops").unwrap();
+		assert_eq!(prepared.error_by_selector(oops.selector()), Some(oops));
+		assert_eq!(prepared.function_by_selector([0; 4]), None);
+		assert!(core::ptr::eq(prepared.contract(), &contract));
+	}
+
+	#[test]
+	fn diff() {
+		let old = r#"
+			[
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint256" }], "outputs": [] },
+				{ "type": "function", "name": "bar", "inputs": [], "outputs": [{ "name": "", "type": "bool" }] },
+				{ "type": "function", "name": "baz", "inputs": [], "outputs": [] },
+				{ "type": "event", "name": "Transfer", "inputs": [{ "name": "from", "type": "address", "indexed": true }], "anonymous": false },
+				{ "type": "error", "name": "Oops", "inputs": [] }
+			]
+		"#;
+		let new = r#"
+			[
+				{ "type": "error", "name": "Oops", "inputs": [] },
+				{ "type": "event", "name": "Transfer", "inputs": [{ "name": "from", "type": "address", "indexed": false }], "anonymous": false },
+				{ "type": "function", "name": "bar", "inputs": [], "outputs": [{ "name": "", "type": "uint8" }] },
+				{ "type": "function", "name": "foo", "inputs": [{ "name": "a", "type": "uint128" }], "outputs": [] },
+				{ "type": "function", "name": "baz", "inputs": [], "outputs": [] }
+			]
+		"#;
+
+		let old: Contract = serde_json::from_str(old).unwrap();
+		let new: Contract = serde_json::from_str(new).unwrap();
+		let diff = old.diff(&new);
+
+		let names = |functions: &[&Function]| functions.iter().map(|f| f.signature()).collect::<Vec<_>>();
+		assert_eq!(names(&diff.functions.added), vec!["foo(uint128)"]);
+		assert_eq!(names(&diff.functions.removed), vec!["foo(uint256)"]);
+		assert_eq!(diff.functions.changed, vec![(old.function("bar").unwrap(), new.function("bar").unwrap())]);
+		assert_eq!(diff.events.changed, vec![(old.event("Transfer").unwrap(), new.event("Transfer").unwrap())]);
+		assert!(diff.events.added.is_empty() && diff.events.removed.is_empty());
+		assert!(diff.errors.is_empty());
+		assert!(!diff.is_empty());
+
+		// reordering entries doesn't change a contract
+		let mut reordered = old.clone();
+		reordered.functions.values_mut().for_each(|functions| functions.reverse());
+		assert!(old.diff(&reordered).is_empty());
+		assert!(new.diff(&new).is_empty());
+	}
+
+	#[test]
+	fn decode_error() {
+		let json = r#"
+			[
+				{ "type": "error", "name": "Unauthorized", "inputs": [{ "name": "caller", "type": "address" }] },
+				{ "type": "error", "name": "InsufficientBalance", "inputs": [
+					{ "name": "available", "type": "uint256" },
+					{ "name": "required", "type": "uint256" }
+				] }
+			]
+		"#;
+		let contract: Contract = serde_json::from_str(json).unwrap();
+
+		let error = contract.error("InsufficientBalance").unwrap();
+		let tokens = vec![Token::Uint(1.into()), Token::Uint(2.into())];
+		let data = error.encode(&tokens).unwrap();
+
+		assert_eq!(contract.error_by_selector(hex!("cf479181")), Some(error));
+		assert_eq!(contract.error_by_selector(hex!("00000000")), None);
+		let (decoded, decoded_tokens) = contract.decode_error(&data).unwrap();
+		assert_eq!(decoded, error);
+		assert_eq!(decoded_tokens, tokens);
+		assert!(contract.decode_error(&data[..3]).is_err());
+		assert!(contract.decode_error(&hex!("08c379a0")).is_err());
+	}
+
+	#[test]
+	fn artifact_object() {
+		let abi = r#"[{ "type": "function", "name": "foo", "inputs": [], "outputs": [] }, { "type": "receive" }]"#;
+		let artifact = format!(
+			r#"{{ "contractName": "Foo", "bytecode": "0x6080", "abi": {abi}, "linkReferences": {{ "a": [1, 2] }} }}"#
+		);
+
+		let expected: Contract = serde_json::from_str(abi).unwrap();
+		assert_eq!(serde_json::from_str::<Contract>(&artifact).unwrap(), expected);
+		assert!(serde_json::from_str::<Contract>(r#"{ "bytecode": "0x6080" }"#).is_err());
+		assert!(serde_json::from_str::<Contract>(r#"{ "abi": [], "abi": [] }"#).is_err());
+	}
+
+	#[test]
+	fn event_by_topic0() {
+		let json = r#"
+			[
+				{ "type": "event", "name": "Transfer", "inputs": [{ "name": "a", "type": "address", "indexed": true }], "anonymous": false },
+				{ "type": "event", "name": "Transfer", "inputs": [{ "name": "a", "type": "uint256", "indexed": true }], "anonymous": false },
+				{ "type": "event", "name": "Anon", "inputs": [], "anonymous": true }
+			]
+		"#;
+		let contract: Contract = serde_json::from_str(json).unwrap();
+
+		for event in contract.events().filter(|event| !event.anonymous) {
+			assert_eq!(contract.event_by_topic0(event.signature()), Some(event));
+		}
+		let transfer = contract.event_by_topic0(contract.events_by_name("Transfer").unwrap()[1].signature()).unwrap();
+		assert_eq!(transfer.inputs[0].kind, ParamType::Uint(256));
+
+		let anon = contract.event("Anon").unwrap();
+		assert_eq!(contract.event_by_topic0(anon.signature()), None);
+		assert_eq!(contract.event_by_topic0(Hash::zero()), None);
+	}
 }
@@ -8,9 +8,11 @@
 
 //! ABI decoder.
 
+use core::fmt;
+
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Error, ParamType, Token, Word};
+use crate::{no_std_prelude::Cow, param_type::heads_size, util::word_at, Error, ParamType, Token, Uint, Word};
 
 #[derive(Debug)]
 struct DecodeResult {
@@ -36,7 +38,37 @@ fn as_bool(slice: &Word) -> Result<bool, Error> {
 	Ok(slice[31] == 1)
 }
 
-fn decode_impl(types: &[ParamType], data: &[u8], validate: bool) -> Result<(Vec<Token>, usize), Error> {
+/// Limits applied while decoding untrusted data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+	/// Maximum number of elements of a single dynamic array.
+	pub max_array_length: usize,
+	/// Maximum nesting depth of arrays and tuples.
+	pub max_depth: usize,
+}
+
+impl DecodeLimits {
+	/// No limits besides the size of the data.
+	pub const UNLIMITED: Self = DecodeLimits { max_array_length: usize::MAX, max_depth: usize::MAX };
+
+	fn nested(&self) -> Result<Self, Error> {
+		let max_depth = self.max_depth.checked_sub(1).ok_or(Error::InvalidData)?;
+		Ok(DecodeLimits { max_depth, ..*self })
+	}
+}
+
+impl Default for DecodeLimits {
+	fn default() -> Self {
+		DecodeLimits { max_array_length: 1 << 16, max_depth: 32 }
+	}
+}
+
+fn decode_impl(
+	types: &[ParamType],
+	data: &[u8],
+	validate: bool,
+	limits: &DecodeLimits,
+) -> Result<(Vec<Token>, usize), Error> {
 	let is_empty_bytes_valid_encoding = types.iter().all(|t| t.is_empty_bytes_valid_encoding());
 	if !is_empty_bytes_valid_encoding && data.is_empty() {
 		return Err(Error::InvalidName(
@@ -48,13 +80,20 @@ fn decode_impl(types: &[ParamType], data: &[u8], validate: bool) -> Result<(Vec<
 		));
 	}
 
+	check_heads_len("data", types, data, validate)?;
+
 	let mut tokens = vec![];
 	tokens.try_reserve_exact(types.len()).map_err(|_| Error::InvalidData)?;
 
 	let mut offset = 0;
 
-	for param in types {
-		let res = decode_param(param, data, offset, validate)?;
+	for (index, param) in types.iter().enumerate() {
+		let res = decode_param(param, data, offset, validate, limits).map_err(|err| Error::DecodeError {
+			index,
+			param: param.clone(),
+			reason: Box::new(err),
+			hint: missing_offset_hint(types, data),
+		})?;
 		offset = res.new_offset;
 		tokens.push(res.token);
 	}
@@ -65,31 +104,120 @@ fn decode_impl(types: &[ParamType], data: &[u8], validate: bool) -> Result<(Vec<
 	Ok((tokens, offset))
 }
 
+fn too_short(what: &str, expected: impl fmt::Display, data: &[u8]) -> Error {
+	Error::Other(Cow::Owned(format!("{what} too short: expected at least {expected} bytes, got {}", data.len())))
+}
+
+/// Checks that `data` holds the heads of all `types`, except for padding which
+/// is only required when validating.
+fn check_heads_len(what: &str, types: &[ParamType], data: &[u8], validate: bool) -> Result<(), Error> {
+	let heads_len = heads_size(types).ok_or(Error::InvalidData)?;
+	let min_len = if validate { heads_len } else { heads_len - trailing_slack(types) };
+	if data.len() < min_len {
+		return Err(too_short(what, min_len, data));
+	}
+	Ok(())
+}
+
+/// Checks that the offset of each dynamic value in the heads of `types` leaves
+/// room for at least one word of its tail.
+fn check_tails_len(what: &str, types: &[ParamType], data: &[u8]) -> Result<(), Error> {
+	let mut head_offset = 0usize;
+	for kind in types {
+		if kind.is_dynamic() {
+			let offset = Uint::from_big_endian(peek_32_bytes(data, head_offset)?);
+			let min_len = offset.saturating_add(Uint::from(32));
+			if min_len > Uint::from(data.len()) {
+				return Err(too_short(what, min_len, data));
+			}
+		}
+		head_offset += kind.head_size().ok_or(Error::InvalidData)?;
+	}
+	Ok(())
+}
+
+/// Decodes the input or output of a function, `what` names which one in errors.
+///
+/// Unlike [`decode`], this also fails if a dynamic value starts past the end of
+/// the data, so truncated data is reported as e.g. "input too short: expected
+/// at least 96 bytes, got 64".
+pub(crate) fn decode_function_data(what: &str, types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, Error> {
+	// empty data is left to `decode`, which hints at a missing contract
+	if !data.is_empty() {
+		check_heads_len(what, types, data, false)?;
+		check_tails_len(what, types, data)?;
+	}
+	decode(types, data)
+}
+
+/// Explains the failure to decode a single dynamic value whose offset points
+/// past the end of the data, which usually means the data is the encoded tail
+/// without the leading offset word.
+fn missing_offset_hint(types: &[ParamType], data: &[u8]) -> Option<Cow<'static, str>> {
+	match types {
+		[param] if param.is_dynamic() => {
+			let offset = Uint::from_big_endian(word_at(data, 0)?);
+			(offset.saturating_add(Uint::from(32)) > Uint::from(data.len())).then(|| {
+				Cow::Owned(format!(
+					"offset {offset} exceeds data length {}, the data may be missing the leading offset word",
+					data.len()
+				))
+			})
+		}
+		_ => None,
+	}
+}
+
 /// Decodes ABI compliant vector of bytes into vector of tokens described by types param.
 /// Checks, that decoded data is exact as input provided
 pub fn decode_validate(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, Error> {
-	decode_impl(types, data, true).map(|(tokens, _)| tokens)
+	decode_impl(types, data, true, &DecodeLimits::UNLIMITED).map(|(tokens, _)| tokens)
 }
 
 /// Decodes ABI compliant vector of bytes into vector of tokens described by types param.
 pub fn decode(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, Error> {
-	decode_impl(types, data, false).map(|(tokens, _)| tokens)
+	decode_impl(types, data, false, &DecodeLimits::UNLIMITED).map(|(tokens, _)| tokens)
 }
 
-fn peek(data: &[u8], offset: usize, len: usize) -> Result<&[u8], Error> {
-	if offset + len > data.len() {
-		Err(Error::InvalidData)
-	} else {
-		Ok(&data[offset..(offset + len)])
+/// Decodes ABI compliant vector of bytes into vector of tokens described by types param.
+/// Fails if an array is longer or arrays and tuples are nested deeper than allowed by the
+/// given limits.
+pub fn decode_limited(types: &[ParamType], data: &[u8], limits: &DecodeLimits) -> Result<Vec<Token>, Error> {
+	decode_impl(types, data, false, limits).map(|(tokens, _)| tokens)
+}
+
+/// Decodes ABI encoded data whose last word may be cut short, as emitted by some
+/// non-compliant contracts.
+///
+/// The data is right padded with zeros to a multiple of 32 bytes before being
+/// decoded like [`decode`] does. Only use this when the data is known to come
+/// from such a contract, compliant data should be decoded with [`decode`].
+pub fn decode_lenient(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, Error> {
+	let padded_len = round_up_nearest_multiple(data.len(), 32);
+	if padded_len == data.len() {
+		return decode(types, data);
 	}
+	let mut padded = data.to_vec();
+	padded.resize(padded_len, 0);
+	decode(types, &padded)
 }
 
-fn peek_32_bytes(data: &[u8], offset: usize) -> Result<Word, Error> {
-	peek(data, offset, 32).map(|x| {
-		let mut out: Word = [0u8; 32];
-		out.copy_from_slice(&x[0..32]);
-		out
-	})
+/// Number of bytes at the end of the heads of `types` which are only padding
+/// and need not be present when decoding without validation, which is the case
+/// for the trailing zeros of a `bytesN` value ending the heads.
+fn trailing_slack(types: &[ParamType]) -> usize {
+	match types.iter().rev().find(|kind| kind.head_size() != Some(0)) {
+		Some(ParamType::FixedBytes(len)) => 32usize.saturating_sub(*len),
+		Some(kind @ ParamType::FixedArray(inner, _)) if !kind.is_dynamic() => {
+			trailing_slack(core::slice::from_ref(inner))
+		}
+		Some(kind @ ParamType::Tuple(inner)) if !kind.is_dynamic() => trailing_slack(inner),
+		_ => 0,
+	}
+}
+
+fn peek_32_bytes(data: &[u8], offset: usize) -> Result<&Word, Error> {
+	word_at(data, offset).ok_or(Error::InvalidData)
 }
 
 fn round_up_nearest_multiple(value: usize, padding: usize) -> usize {
@@ -97,14 +225,23 @@ fn round_up_nearest_multiple(value: usize, padding: usize) -> usize {
 }
 
 fn take_bytes(data: &[u8], offset: usize, len: usize, validate: bool) -> Result<Vec<u8>, Error> {
+	if offset > data.len() {
+		return Err(Error::Other(Cow::Owned(format!("offset {offset} exceeds data length {}", data.len()))));
+	}
+	let available = data.len() - offset;
+	if len > available {
+		return Err(Error::Other(Cow::Owned(format!(
+			"declared length {len} exceeds available {available} bytes at offset {offset}"
+		))));
+	}
 	if validate {
 		let padded_len = round_up_nearest_multiple(len, 32);
-		if offset + padded_len > data.len() {
-			return Err(Error::InvalidData);
+		if padded_len > available {
+			return Err(Error::Other(Cow::Owned(format!(
+				"padded length {padded_len} exceeds available {available} bytes at offset {offset}"
+			))));
 		}
 		check_zeroes(&data[(offset + len)..(offset + padded_len)])?;
-	} else if offset + len > data.len() {
-		return Err(Error::InvalidData);
 	}
 	Ok(data[offset..(offset + len)].to_vec())
 }
@@ -117,7 +254,23 @@ fn check_zeroes(data: &[u8]) -> Result<(), Error> {
 	}
 }
 
-fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -> Result<DecodeResult, Error> {
+fn read_dynamic_offset(data: &[u8], offset: usize, validate: bool) -> Result<usize, Error> {
+	let dynamic_offset = as_usize(peek_32_bytes(data, offset)?)?;
+	// Tails always follow the head, so an offset pointing back at itself or into the
+	// already consumed part of the head can only come from a malformed encoding.
+	if validate && dynamic_offset < offset + 32 {
+		return Err(Error::InvalidData);
+	}
+	Ok(dynamic_offset)
+}
+
+fn decode_param(
+	param: &ParamType,
+	data: &[u8],
+	offset: usize,
+	validate: bool,
+	limits: &DecodeLimits,
+) -> Result<DecodeResult, Error> {
 	match *param {
 		ParamType::Address => {
 			let slice = peek_32_bytes(data, offset)?;
@@ -140,7 +293,7 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 			Ok(result)
 		}
 		ParamType::Bool => {
-			let b = as_bool(&peek_32_bytes(data, offset)?)?;
+			let b = as_bool(peek_32_bytes(data, offset)?)?;
 			let result = DecodeResult { token: Token::Bool(b), new_offset: offset + 32 };
 			Ok(result)
 		}
@@ -152,15 +305,15 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 			Ok(result)
 		}
 		ParamType::Bytes => {
-			let dynamic_offset = as_usize(&peek_32_bytes(data, offset)?)?;
-			let len = as_usize(&peek_32_bytes(data, dynamic_offset)?)?;
+			let dynamic_offset = read_dynamic_offset(data, offset, validate)?;
+			let len = as_usize(peek_32_bytes(data, dynamic_offset)?)?;
 			let bytes = take_bytes(data, dynamic_offset + 32, len, validate)?;
 			let result = DecodeResult { token: Token::Bytes(bytes), new_offset: offset + 32 };
 			Ok(result)
 		}
 		ParamType::String => {
-			let dynamic_offset = as_usize(&peek_32_bytes(data, offset)?)?;
-			let len = as_usize(&peek_32_bytes(data, dynamic_offset)?)?;
+			let dynamic_offset = read_dynamic_offset(data, offset, validate)?;
+			let len = as_usize(peek_32_bytes(data, dynamic_offset)?)?;
 			let bytes = take_bytes(data, dynamic_offset + 32, len, validate)?;
 			let result = DecodeResult {
 				// NOTE: We're decoding strings using lossy UTF-8 decoding to
@@ -173,18 +326,25 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 			Ok(result)
 		}
 		ParamType::Array(ref t) => {
-			let len_offset = as_usize(&peek_32_bytes(data, offset)?)?;
-			let len = as_usize(&peek_32_bytes(data, len_offset)?)?;
+			let len_offset = read_dynamic_offset(data, offset, validate)?;
+			let len = as_usize(peek_32_bytes(data, len_offset)?)?;
 
 			let tail_offset = len_offset + 32;
 			let tail = &data[tail_offset..];
 
+			// Every element takes up space in the tail, so longer arrays can't be backed by the data.
+			let min_size = t.head_size().ok_or(Error::InvalidData)?;
+			if len > limits.max_array_length || (min_size > 0 && len > tail.len() / min_size) {
+				return Err(Error::InvalidData);
+			}
+			let limits = limits.nested()?;
+
 			let mut tokens = vec![];
 			tokens.try_reserve_exact(len).map_err(|_| Error::InvalidData)?;
 			let mut new_offset = 0;
 
 			for _ in 0..len {
-				let res = decode_param(t, tail, new_offset, validate)?;
+				let res = decode_param(t, tail, new_offset, validate, &limits)?;
 				new_offset = res.new_offset;
 				tokens.push(res.token);
 			}
@@ -197,7 +357,7 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 			let is_dynamic = param.is_dynamic();
 
 			let (tail, mut new_offset) = if is_dynamic {
-				let offset = as_usize(&peek_32_bytes(data, offset)?)?;
+				let offset = read_dynamic_offset(data, offset, validate)?;
 				if offset > data.len() {
 					return Err(Error::InvalidData);
 				}
@@ -206,11 +366,12 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 				(data, offset)
 			};
 
+			let limits = limits.nested()?;
 			let mut tokens = vec![];
 			tokens.try_reserve_exact(len).map_err(|_| Error::InvalidData)?;
 
 			for _ in 0..len {
-				let res = decode_param(t, tail, new_offset, validate)?;
+				let res = decode_param(t, tail, new_offset, validate, &limits)?;
 				new_offset = res.new_offset;
 				tokens.push(res.token);
 			}
@@ -228,7 +389,7 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 			// The first element in a dynamic Tuple is an offset to the Tuple's data
 			// For a static Tuple the data begins right away
 			let (tail, mut new_offset) = if is_dynamic {
-				let offset = as_usize(&peek_32_bytes(data, offset)?)?;
+				let offset = read_dynamic_offset(data, offset, validate)?;
 				if offset > data.len() {
 					return Err(Error::InvalidData);
 				}
@@ -237,10 +398,11 @@ fn decode_param(param: &ParamType, data: &[u8], offset: usize, validate: bool) -
 				(data, offset)
 			};
 
+			let limits = limits.nested()?;
 			let len = t.len();
 			let mut tokens = Vec::with_capacity(len);
 			for param in t {
-				let res = decode_param(param, tail, new_offset, validate)?;
+				let res = decode_param(param, tail, new_offset, validate, &limits)?;
 				new_offset = res.new_offset;
 				tokens.push(res.token);
 			}
@@ -264,7 +426,7 @@ mod tests {
 
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{decode, decode_validate, ParamType, Token, Uint};
+	use crate::{decode, decode_lenient, decode_limited, decode_validate, DecodeLimits, Error, ParamType, Token, Uint};
 
 	#[test]
 	fn decode_from_empty_byte_slice() {
@@ -554,6 +716,195 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn decode_empty_bytes_and_string() {
+		let encoded = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000060
+			0000000000000000000000000000000000000000000000000000000000000080
+			0000000000000000000000000000000000000000000000000000000000000007
+			0000000000000000000000000000000000000000000000000000000000000000
+			0000000000000000000000000000000000000000000000000000000000000000
+		"
+		);
+
+		assert_eq!(
+			decode(&[ParamType::Bytes, ParamType::String, ParamType::Uint(256)], &encoded).unwrap(),
+			&[Token::Bytes(vec![]), Token::String("".into()), Token::Uint(Uint::from(7))]
+		);
+	}
+
+	#[test]
+	fn decode_missing_offset_hint() {
+		// the tail of an encoded `bytes` value without its offset
+		let tail = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000040
+			0000000000000000000000000000000000000000000000000000000000000000
+			"
+		);
+		// the hint comes along with the actual reason
+		match decode(&[ParamType::Bytes], &tail) {
+			Err(Error::DecodeError { index: 0, param: ParamType::Bytes, reason, hint: Some(hint) }) => {
+				assert!(matches!(*reason, Error::InvalidData));
+				assert_eq!(hint, "offset 64 exceeds data length 64, the data may be missing the leading offset word");
+			}
+			other => panic!("unexpected result {other:?}"),
+		}
+		#[cfg(feature = "std")]
+		assert_eq!(
+			decode(&[ParamType::Bytes], &tail).unwrap_err().to_string(),
+			"Cannot decode argument 0 of type bytes: Invalid data, \
+			 offset 64 exceeds data length 64, the data may be missing the leading offset word"
+		);
+
+		// no hint when the dynamic value is not the only one
+		let err = decode(&[ParamType::Bytes, ParamType::Bytes], &tail).unwrap_err();
+		assert!(matches!(err, Error::DecodeError { hint: None, .. }), "{err:?}");
+	}
+
+	#[test]
+	fn decode_shared_tail() {
+		let encoded = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000060
+			0000000000000000000000000000000000000000000000000000000000000060
+			0000000000000000000000000000000000000000000000000000000000000007
+			0000000000000000000000000000000000000000000000000000000000000002
+			1234000000000000000000000000000000000000000000000000000000000000
+		"
+		);
+
+		// both heads point at the same tail, the param after them is still read from the head
+		let bytes = Token::Bytes(vec![0x12, 0x34]);
+		assert_eq!(
+			decode(&[ParamType::Bytes, ParamType::Bytes, ParamType::Uint(256)], &encoded).unwrap(),
+			vec![bytes.clone(), bytes, Token::Uint(7.into())]
+		);
+	}
+
+	#[test]
+	fn decode_data_shorter_than_heads() {
+		let word = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let assert_too_short = |types: &[ParamType], data: &[u8], expected: &str| match decode(types, data) {
+			Err(Error::Other(msg)) => assert_eq!(msg, expected),
+			other => panic!("unexpected result {other:?}"),
+		};
+
+		assert_too_short(
+			&[ParamType::Uint(256), ParamType::Uint(256)],
+			&word,
+			"data too short: expected at least 64 bytes, got 32",
+		);
+		assert_too_short(
+			&[ParamType::Bytes, ParamType::FixedArray(Box::new(ParamType::Address), 2)],
+			&[0u8; 90],
+			"data too short: expected at least 96 bytes, got 90",
+		);
+		assert!(matches!(
+			decode_validate(&[ParamType::Uint(256), ParamType::FixedBytes(1)], &[0u8; 33]),
+			Err(Error::Other(_))
+		));
+
+		// the padding of trailing fixed bytes is not required when not validating
+		let types = [ParamType::Uint(256), ParamType::Tuple(vec![ParamType::Bool, ParamType::FixedBytes(1)])];
+		assert_eq!(
+			decode(&types, &[&word[..], &word[..], &[0x12]].concat()).unwrap(),
+			vec![Token::Uint(1.into()), Token::Tuple(vec![Token::Bool(true), Token::FixedBytes(vec![0x12])])]
+		);
+
+		// oversized fixed bytes have no padding to leave out
+		assert_too_short(
+			&[ParamType::FixedBytes(40)],
+			&[0u8; 16],
+			"data too short: expected at least 32 bytes, got 16",
+		);
+	}
+
+	#[test]
+	fn decode_heads_size_overflow() {
+		let huge = |len| ParamType::FixedArray(Box::new(ParamType::Uint(256)), len);
+		let data = [0u8; 32];
+
+		// the sizes of the heads don't fit in a usize
+		assert!(matches!(decode(&[huge(usize::MAX / 16)], &data), Err(Error::InvalidData)));
+		assert!(matches!(decode(&[huge(1 << 60)], &data), Err(Error::InvalidData)));
+		assert!(matches!(decode(&[huge(usize::MAX / 32), huge(usize::MAX / 32)], &data), Err(Error::InvalidData)));
+		let tuple = ParamType::Tuple(vec![huge(usize::MAX / 32), huge(usize::MAX / 32)]);
+		assert!(matches!(decode(&[tuple], &data), Err(Error::InvalidData)));
+		assert!(decode(&[ParamType::Array(Box::new(huge(usize::MAX / 16)))], &[0u8; 64]).is_err());
+	}
+
+	#[test]
+	fn take_zero_bytes_at_end_of_data() {
+		let data = [0u8; 64];
+		assert_eq!(super::take_bytes(&data, 64, 0, false).unwrap(), Vec::<u8>::new());
+		assert_eq!(super::take_bytes(&data, 64, 0, true).unwrap(), Vec::<u8>::new());
+		assert!(super::take_bytes(&data, 65, 0, false).is_err());
+	}
+
+	#[test]
+	fn decode_lenient_pads_last_word() {
+		// trailing zeros of the bytes32 value are missing
+		let encoded = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000007
+			1234
+		"
+		);
+		let types = [ParamType::Uint(256), ParamType::FixedBytes(32)];
+
+		assert!(decode(&types, &encoded).is_err());
+		let mut fixed_bytes = vec![0u8; 32];
+		fixed_bytes[..2].copy_from_slice(&hex!("1234"));
+		assert_eq!(
+			decode_lenient(&types, &encoded).unwrap(),
+			vec![Token::Uint(7.into()), Token::FixedBytes(fixed_bytes)]
+		);
+		assert_eq!(decode_lenient(&types[..1], &encoded[..32]).unwrap(), vec![Token::Uint(7.into())]);
+	}
+
+	#[test]
+	fn decode_oversized_length() {
+		let encoded = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000041
+			1111111111111111111111111111111111111111111111111111111111111111
+			"
+		);
+
+		match decode(&[ParamType::Bytes], &encoded) {
+			Err(Error::DecodeError { reason, .. }) => match *reason {
+				Error::Other(msg) => assert_eq!(msg, "declared length 65 exceeds available 32 bytes at offset 64"),
+				other => panic!("unexpected reason: {other:?}"),
+			},
+			other => panic!("unexpected result: {other:?}"),
+		}
+	}
+
+	#[test]
+	fn decode_error_reports_argument() {
+		let encoded = hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000060
+			0000000000000000000000000000000000000000000000000000000000000002
+			00000000000000000000000000000000000000000000000000000000000000ff
+			"
+		);
+		let types = [ParamType::Bool, ParamType::Bytes, ParamType::Uint(256)];
+
+		match decode(&types, &encoded) {
+			Err(Error::DecodeError { index, param, reason, hint: None }) => {
+				assert_eq!(index, 1);
+				assert_eq!(param, ParamType::Bytes);
+				assert!(matches!(*reason, Error::Other(_)));
+			}
+			other => panic!("unexpected result: {other:?}"),
+		}
+	}
+
 	#[test]
 	fn decode_broken_utf8() {
 		let encoded = hex!(
@@ -656,6 +1007,7 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 						name: "c".to_string(),
 						kind: Array(Box::new(Tuple(vec![Uint(256), Uint(256)]))),
 						internal_type: None,
+						components: vec![],
 					},
 					Param {
 						name: "d".to_string(),
@@ -664,6 +1016,7 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 							Array(Box::new(Tuple(vec![Uint(256), Array(Box::new(ParamType::String))]))),
 						]))),
 						internal_type: None,
+						components: vec![],
 					},
 				],
 				outputs: vec![],
@@ -696,11 +1049,12 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 			Function {
 				name: "f".to_string(),
 				inputs: vec![
-					Param { name: "i".to_string(), kind: Uint(256), internal_type: None },
+					Param { name: "i".to_string(), kind: Uint(256), internal_type: None, components: vec![] },
 					Param {
 						name: "p".to_string(),
 						kind: FixedArray(Box::new(ParamType::String), 2),
 						internal_type: None,
+						components: vec![],
 					},
 				],
 				outputs: vec![],
@@ -735,4 +1089,68 @@ ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
 		assert!(decode_validate(&[ParamType::Address, ParamType::FixedBytes(20)], &input).is_err());
 		assert!(decode_validate(&[ParamType::Address, ParamType::Address], &input).is_ok());
 	}
+
+	#[test]
+	fn decode_validate_rejects_backward_offsets() {
+		// offset of the bytes points back at itself
+		let input = hex!(
+			"
+		0000000000000000000000000000000000000000000000000000000000000000
+		"
+		);
+		assert_eq!(decode(&[ParamType::Bytes], &input).unwrap(), vec![Token::Bytes(vec![])]);
+		assert!(decode_validate(&[ParamType::Bytes], &input).is_err());
+
+		// offset of the bytes points back at the already decoded uint
+		let input = hex!(
+			"
+		0000000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000000
+		"
+		);
+		let types = [ParamType::Uint(256), ParamType::Bytes];
+		assert_eq!(decode(&types, &input).unwrap(), vec![Token::Uint(Uint::zero()), Token::Bytes(vec![])]);
+		assert!(decode_validate(&types, &input).is_err());
+	}
+
+	#[test]
+	fn decode_array_length_not_backed_by_data() {
+		let input = hex!(
+			"
+		0000000000000000000000000000000000000000000000000000000000000020
+		00000000000000000000000000000000000000000000000000000000ffffffff
+		0000000000000000000000000000000000000000000000000000000000000001
+		"
+		);
+		assert!(decode(&[ParamType::Array(Box::new(ParamType::Uint(256)))], &input).is_err());
+		assert!(decode(&[ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bool; 2])))], &input).is_err());
+	}
+
+	#[test]
+	fn decode_with_limits() {
+		let input = hex!(
+			"
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000002
+		"
+		);
+		let types = [ParamType::Array(Box::new(ParamType::Uint(256)))];
+		let expected = vec![Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())])];
+
+		assert_eq!(decode_limited(&types, &input, &DecodeLimits::default()).unwrap(), expected);
+		assert_eq!(
+			decode_limited(&types, &input, &DecodeLimits { max_array_length: 2, max_depth: 1 }).unwrap(),
+			expected
+		);
+		assert!(decode_limited(&types, &input, &DecodeLimits { max_array_length: 1, max_depth: 1 }).is_err());
+		assert!(decode_limited(&types, &input, &DecodeLimits { max_array_length: 2, max_depth: 0 }).is_err());
+
+		// zero sized elements are only bounded by the limits
+		let types = [ParamType::Array(Box::new(ParamType::Tuple(vec![])))];
+		assert_eq!(decode(&types, &input).unwrap(), vec![Token::Array(vec![Token::Tuple(vec![]); 2])]);
+		assert!(decode_limited(&types, &input, &DecodeLimits { max_array_length: 1, max_depth: 2 }).is_err());
+		assert!(decode_limited(&types, &input, &DecodeLimits { max_array_length: 2, max_depth: 1 }).is_err());
+	}
 }
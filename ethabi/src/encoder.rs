@@ -8,9 +8,11 @@
 
 //! ABI encoder.
 
+use sha3::{Digest, Keccak256};
+
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{util::pad_u32, Bytes, Token, Word};
+use crate::{util::pad_u32, Bytes, Hash, Token, Word};
 
 fn pad_bytes_len(bytes: &[u8]) -> u32 {
 	// "+ 1" because len is also appended
@@ -103,9 +105,80 @@ impl Mediate<'_> {
 
 /// Encodes vector of tokens into ABI compliant vector of bytes.
 pub fn encode(tokens: &[Token]) -> Bytes {
+	encode_words(tokens).into_iter().flatten().collect()
+}
+
+/// Encodes vector of tokens into ABI compliant vector of 32 byte words.
+///
+/// This is the same encoding as [`encode`] before the words are concatenated,
+/// which is convenient when inspecting the heads and tails of an encoding.
+pub fn encode_words(tokens: &[Token]) -> Vec<Word> {
 	let mediates = &tokens.iter().map(mediate_token).collect::<Vec<_>>();
 
-	encode_head_tail(mediates).into_iter().flatten().collect()
+	encode_head_tail(mediates)
+}
+
+/// Returns the length in bytes of the ABI encoding of the tokens, without encoding them.
+///
+/// `encoded_size(tokens) == encode(tokens).len()` always holds.
+pub fn encoded_size(tokens: &[Token]) -> usize {
+	tokens.iter().map(|token| head_size(token) + tail_size(token)).sum()
+}
+
+fn head_size(token: &Token) -> usize {
+	match token {
+		_ if token.is_dynamic() => 32,
+		Token::FixedBytes(bytes) => 32 * fixed_bytes_len(bytes) as usize,
+		Token::FixedArray(tokens) | Token::Tuple(tokens) => encoded_size(tokens),
+		_ => 32,
+	}
+}
+
+fn tail_size(token: &Token) -> usize {
+	match token {
+		_ if !token.is_dynamic() => 0,
+		Token::Bytes(bytes) => 32 * pad_bytes_len(bytes) as usize,
+		Token::String(s) => 32 * pad_bytes_len(s.as_bytes()) as usize,
+		// + 32 for the length of the array prepended to its elements
+		Token::Array(tokens) => 32 + encoded_size(tokens),
+		Token::FixedArray(tokens) | Token::Tuple(tokens) => encoded_size(tokens),
+		_ => 0,
+	}
+}
+
+/// Encodes a token the way it is stored in a log topic when used as an
+/// indexed event parameter.
+///
+/// Values encoded as a single word are stored as is. Strings and bytes are
+/// stored as the Keccak-256 hash of their contents, arrays and tuples as the
+/// hash of the concatenation of their elements encoded in place: padded to a
+/// multiple of 32 bytes and without any offsets or lengths.
+pub fn encode_topic(token: &Token) -> Hash {
+	let hash = |data: &[u8]| Hash::from_slice(Keccak256::digest(data).as_slice());
+	let mut data = vec![];
+	match token {
+		Token::Bytes(bytes) => hash(bytes),
+		Token::String(s) => hash(s.as_bytes()),
+		Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+			tokens.iter().for_each(|token| encode_in_place_append(&mut data, token));
+			hash(&data.concat())
+		}
+		_ => {
+			encode_token_append(&mut data, token);
+			data.first().copied().unwrap_or_default().into()
+		}
+	}
+}
+
+fn encode_in_place_append(data: &mut Vec<Word>, token: &Token) {
+	match token {
+		Token::Bytes(bytes) => fixed_bytes_append(data, bytes),
+		Token::String(s) => fixed_bytes_append(data, s.as_bytes()),
+		Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+			tokens.iter().for_each(|token| encode_in_place_append(data, token))
+		}
+		_ => encode_token_append(data, token),
+	}
 }
 
 fn encode_head_tail(mediates: &[Mediate]) -> Vec<Word> {
@@ -183,7 +256,83 @@ mod tests {
 
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{encode, util::pad_u32, Token};
+	use crate::{encode, encode_topic, encode_words, encoded_size, util::pad_u32, Token};
+
+	#[test]
+	fn encode_words_splits_encoding() {
+		let tokens = [Token::Uint(7.into()), Token::Bytes(vec![0x11; 3])];
+		let words = encode_words(&tokens);
+		assert_eq!(
+			words,
+			vec![
+				hex!("0000000000000000000000000000000000000000000000000000000000000007"),
+				hex!("0000000000000000000000000000000000000000000000000000000000000040"),
+				hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+				hex!("1111110000000000000000000000000000000000000000000000000000000000"),
+			]
+		);
+		assert_eq!(words.concat(), encode(&tokens));
+	}
+
+	#[test]
+	fn encoded_size_matches_encode() {
+		let uint = Token::Uint(1.into());
+		let bytes = Token::Bytes(vec![0x11; 33]);
+		let string = Token::String("gavofyork".into());
+		let fixed_bytes = Token::FixedBytes(vec![0x22; 2]);
+		let cases = vec![
+			vec![],
+			vec![uint.clone()],
+			vec![bytes.clone()],
+			vec![Token::Bytes(vec![])],
+			vec![fixed_bytes.clone(), string.clone()],
+			vec![Token::Array(vec![])],
+			vec![Token::Array(vec![uint.clone(), uint.clone()])],
+			vec![Token::FixedArray(vec![uint.clone(), fixed_bytes.clone()])],
+			vec![Token::FixedArray(vec![string.clone(), bytes.clone()])],
+			vec![Token::Tuple(vec![
+				uint.clone(),
+				Token::Array(vec![Token::Tuple(vec![string.clone(), uint.clone()])]),
+			])],
+			vec![
+				Token::Array(vec![Token::Array(vec![bytes.clone()]), Token::Array(vec![])]),
+				Token::Tuple(vec![uint, fixed_bytes]),
+				string,
+			],
+		];
+		for tokens in cases {
+			assert_eq!(encoded_size(&tokens), encode(&tokens).len(), "{:?}", tokens);
+		}
+	}
+
+	#[test]
+	fn encode_topics() {
+		let address = Token::Address([0x11u8; 20].into());
+		assert_eq!(encode_topic(&address).0, hex!("0000000000000000000000001111111111111111111111111111111111111111"));
+		assert_eq!(
+			encode_topic(&Token::FixedBytes(vec![0x12, 0x34])).0,
+			hex!("1234000000000000000000000000000000000000000000000000000000000000")
+		);
+
+		// keccak256("hello")
+		let hello = hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+		assert_eq!(encode_topic(&Token::String("hello".into())).0, hello);
+		assert_eq!(encode_topic(&Token::Bytes(b"hello".to_vec())).0, hello);
+
+		// keccak256(abi.encodePacked(uint256(1), uint256(2)))
+		let one_two = hex!("e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0");
+		let one_two_tokens = vec![Token::Uint(1.into()), Token::Uint(2.into())];
+		assert_eq!(encode_topic(&Token::Array(one_two_tokens.clone())).0, one_two);
+		assert_eq!(encode_topic(&Token::FixedArray(one_two_tokens.clone())).0, one_two);
+		assert_eq!(encode_topic(&Token::Tuple(one_two_tokens)).0, one_two);
+
+		// nested strings are padded and not length prefixed
+		let nested = Token::Tuple(vec![Token::String("hello".into()), Token::Array(vec![Token::Bool(true)])]);
+		let mut in_place = [0u8; 64];
+		in_place[..5].copy_from_slice(b"hello");
+		in_place[63] = 1;
+		assert_eq!(encode_topic(&nested), encode_topic(&Token::Bytes(in_place.to_vec())));
+	}
 
 	#[test]
 	fn encode_address() {
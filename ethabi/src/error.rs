@@ -24,7 +24,7 @@ use crate::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
 	/// Error name.
-	#[cfg_attr(feature = "serde", serde(deserialize_with = "crate::util::sanitize_name::deserialize"))]
+	#[cfg_attr(feature = "serde", serde(deserialize_with = "crate::util::deserialize_sanitized_name"))]
 	pub name: String,
 	/// Error input.
 	pub inputs: Vec<Param>,
@@ -41,6 +41,11 @@ impl Error {
 		long_signature(&self.name, &self.param_types())
 	}
 
+	/// Returns the 4 byte selector prefixing revert data of this error.
+	pub fn selector(&self) -> [u8; 4] {
+		short_signature(&self.name, &self.param_types())
+	}
+
 	/// Prepares ABI error with given input params.
 	pub fn encode(&self, tokens: &[Token]) -> Result<Bytes> {
 		let params = self.param_types();
@@ -58,4 +63,10 @@ impl Error {
 	pub fn decode(&self, data: &[u8]) -> Result<Vec<Token>> {
 		decode(&self.param_types(), data)
 	}
+
+	/// Returns the JSON fragment describing this error in a contract ABI.
+	#[cfg(feature = "full-serde")]
+	pub fn to_json_value(&self) -> serde_json::Value {
+		crate::operation::OperationRef::Error(self).to_json_value()
+	}
 }
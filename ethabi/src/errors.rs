@@ -9,7 +9,7 @@
 use crate::no_std_prelude::Cow;
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-#[cfg(feature = "serde")]
+use crate::ParamType;
 use core::num;
 #[cfg(feature = "std")]
 use thiserror::Error;
@@ -32,13 +32,31 @@ pub enum Error {
 	#[error("Serialization error: {0}")]
 	SerdeJson(#[from] serde_json::Error),
 	/// Integer parsing error.
-	#[cfg(feature = "serde")]
 	#[cfg_attr(feature = "std", error("Integer parsing error: {0}"))]
 	ParseInt(#[cfg_attr(feature = "std", from)] num::ParseIntError),
 	/// Hex string parsing error.
 	#[cfg(feature = "serde")]
 	#[cfg_attr(feature = "std", error("Hex parsing error: {0}"))]
 	Hex(#[cfg_attr(feature = "std", from)] hex::FromHexError),
+	/// Failed to decode one of several values.
+	#[cfg_attr(
+		feature = "std",
+		error(
+			"Cannot decode argument {index} of type {param}: {reason}{}",
+			.hint.as_ref().map(|hint| format!(", {hint}")).unwrap_or_default()
+		)
+	)]
+	DecodeError {
+		/// Position of the value which failed to decode.
+		index: usize,
+		/// Type of the value which failed to decode.
+		param: ParamType,
+		/// Why decoding failed.
+		#[cfg_attr(feature = "std", source)]
+		reason: Box<Error>,
+		/// A guess at what is wrong with the data, if there is one.
+		hint: Option<Cow<'static, str>>,
+	},
 	/// Other errors.
 	#[cfg_attr(feature = "std", error("{0}"))]
 	Other(Cow<'static, str>),
@@ -12,21 +12,25 @@ use alloc::collections::BTreeMap;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Keccak256};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-	decode, decode_validate, encode, signature::long_signature, Error, EventParam, Hash, Log, LogParam, ParamType,
-	RawLog, RawTopicFilter, Result, Token, Topic, TopicFilter,
+	decode, decode_validate, encode_topic,
+	no_std_prelude::Cow,
+	signature::{canonical_signature, long_signature},
+	Error, EventParam, Hash, Log, LogParam, ParamType, RawLog, RawTopicFilter, Result, Token, Topic, TopicFilter,
 };
 
 /// Contract event.
+///
+/// Deserialization fails for events with more indexed params than fit in
+/// the topics of a log, see [`validate`](Event::validate).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "EventRepr"))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Event {
 	/// Event name.
-	#[cfg_attr(feature = "serde", serde(deserialize_with = "crate::util::sanitize_name::deserialize"))]
 	pub name: String,
 	/// Event input.
 	pub inputs: Vec<EventParam>,
@@ -34,6 +38,27 @@ pub struct Event {
 	pub anonymous: bool,
 }
 
+/// Serialized form of an event, checked before being turned into an `Event`.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct EventRepr {
+	#[serde(deserialize_with = "crate::util::deserialize_sanitized_name")]
+	name: String,
+	inputs: Vec<EventParam>,
+	anonymous: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<EventRepr> for Event {
+	type Error = String;
+
+	fn try_from(repr: EventRepr) -> core::result::Result<Self, Self::Error> {
+		let event = Event { name: repr.name, inputs: repr.inputs, anonymous: repr.anonymous };
+		event.check_indexed_params()?;
+		Ok(event)
+	}
+}
+
 impl Event {
 	/// Returns names of all params.
 	fn params_names(&self) -> Vec<String> {
@@ -50,25 +75,51 @@ impl Event {
 		self.inputs.iter().filter(|p| p.indexed == indexed).cloned().collect()
 	}
 
+	/// Checks that the indexed params of this event fit in the topics of a
+	/// log, that is at most 3 for regular events and 4 for anonymous ones.
+	pub fn validate(&self) -> Result<()> {
+		self.check_indexed_params().map_err(|msg| Error::Other(Cow::Owned(msg)))
+	}
+
+	fn check_indexed_params(&self) -> core::result::Result<(), String> {
+		let max = if self.anonymous { 4 } else { 3 };
+		let indexed = self.inputs.iter().filter(|p| p.indexed).count();
+		if indexed > max {
+			let kind = if self.anonymous { "anonymous event" } else { "event" };
+			return Err(format!("{kind} `{}` has {indexed} indexed params, at most {max} are allowed", self.name));
+		}
+		Ok(())
+	}
+
 	/// Event signature
 	pub fn signature(&self) -> Hash {
 		long_signature(&self.name, &self.param_types())
 	}
 
+	/// Returns the canonical signature of this event, which is hashed to compute
+	/// its [`signature`](Event::signature). Param names and `indexed` are omitted
+	/// and tuples are expanded into their component types.
+	///
+	/// Examples:
+	/// - `Transfer(address,address,uint256)`
+	/// - `OrderFilled((address,uint256)[],bytes32)`
+	pub fn signature_string(&self) -> String {
+		canonical_signature(&self.name, self.inputs.iter().map(|p| &p.kind))
+	}
+
+	/// Returns the JSON fragment describing this event in a contract ABI.
+	#[cfg(feature = "full-serde")]
+	pub fn to_json_value(&self) -> serde_json::Value {
+		crate::operation::OperationRef::Event(self).to_json_value()
+	}
+
 	/// Creates topic filter
 	pub fn filter(&self, raw: RawTopicFilter) -> Result<TopicFilter> {
 		fn convert_token(token: Token, kind: &ParamType) -> Result<Hash> {
 			if !token.type_check(kind) {
 				return Err(Error::InvalidData);
 			}
-			let encoded = encode(&[token]);
-			if encoded.len() == 32 {
-				let mut data = [0u8; 32];
-				data.copy_from_slice(&encoded);
-				Ok(data.into())
-			} else {
-				Ok(Hash::from_slice(Keccak256::digest(&encoded).as_slice()))
-			}
+			Ok(encode_topic(&token))
 		}
 
 		fn convert_topic(topic: Topic<Token>, kind: Option<&ParamType>) -> Result<Topic<Hash>> {
@@ -95,9 +146,12 @@ impl Event {
 				topic0: convert_topic(raw.topic0, kinds.get(0))?,
 				topic1: convert_topic(raw.topic1, kinds.get(1))?,
 				topic2: convert_topic(raw.topic2, kinds.get(2))?,
-				topic3: Topic::Any,
+				topic3: convert_topic(raw.topic3, kinds.get(3))?,
 			}
 		} else {
+			if !raw.topic3.is_any() {
+				return Err(Error::InvalidData);
+			}
 			TopicFilter {
 				topic0: Topic::This(self.signature()),
 				topic1: convert_topic(raw.topic0, kinds.get(0))?,
@@ -124,68 +178,126 @@ impl Event {
 		}
 	}
 
-	fn parse_log_inner<F: Fn(&[ParamType], &[u8]) -> Result<Vec<Token>>>(&self, log: RawLog, decode: F) -> Result<Log> {
+	/// Computes the parts of this event needed to parse its logs.
+	fn log_layout(&self) -> LogLayout {
+		let topic_params = self.indexed_params(true);
+		let data_params = self.indexed_params(false);
+		LogLayout {
+			anonymous: self.anonymous,
+			signature: self.signature(),
+			names: self.params_names(),
+			topic_types: topic_params.iter().map(|p| self.convert_topic_param_type(&p.kind)).collect(),
+			topic_names: topic_params.into_iter().map(|p| p.name).collect(),
+			data_types: data_params.iter().map(|p| p.kind.clone()).collect(),
+			data_names: data_params.into_iter().map(|p| p.name).collect(),
+		}
+	}
+
+	/// Parses `RawLog` and retrieves all log params from it.
+	/// Checks, that decoded data is exact as input provided
+	pub fn parse_log_validate(&self, log: RawLog) -> Result<Log> {
+		self.log_layout().parse(log, decode_validate, true)
+	}
+
+	/// Parses `RawLog` and retrieves all log params from it.
+	pub fn parse_log(&self, log: RawLog) -> Result<Log> {
+		self.log_layout().parse(log, decode, true)
+	}
+
+	/// Parses `RawLog` and retrieves all log params from it without checking
+	/// that the first topic matches the event signature.
+	///
+	/// The first topic of a non-anonymous event is still skipped. This is only
+	/// useful if the log was already matched to this event by other means, for
+	/// example when it was emitted on behalf of a proxy.
+	pub fn parse_log_unchecked(&self, log: RawLog) -> Result<Log> {
+		self.log_layout().parse(log, decode, false)
+	}
+
+	/// Parses a batch of `RawLog`s, e.g. as returned by `eth_getLogs`.
+	///
+	/// Equivalent to calling [`parse_log`](Event::parse_log) on every log, but the
+	/// event signature and the split into indexed and data params are only
+	/// computed once for the whole batch.
+	pub fn parse_logs<I: IntoIterator<Item = RawLog>>(&self, logs: I) -> impl Iterator<Item = Result<Log>> {
+		let layout = self.log_layout();
+		logs.into_iter().map(move |log| layout.parse(log, decode, true))
+	}
+
+	/// Parses `RawLog` and retrieves all log params from it, keyed by param
+	/// name. If several params share a name, only the last one is kept.
+	pub fn parse_log_map(&self, log: RawLog) -> Result<BTreeMap<String, Token>> {
+		let log = self.parse_log(log)?;
+		Ok(log.params.into_iter().map(|param| (param.name, param.value)).collect())
+	}
+}
+
+/// Parts of an event needed to parse its logs.
+struct LogLayout {
+	anonymous: bool,
+	signature: Hash,
+	/// Names of all params, in order.
+	names: Vec<String>,
+	topic_names: Vec<String>,
+	topic_types: Vec<ParamType>,
+	data_names: Vec<String>,
+	data_types: Vec<ParamType>,
+}
+
+impl LogLayout {
+	fn parse<F: Fn(&[ParamType], &[u8]) -> Result<Vec<Token>>>(
+		&self,
+		log: RawLog,
+		decode: F,
+		check_signature: bool,
+	) -> Result<Log> {
 		let topics = log.topics;
 		let data = log.data;
 		let topics_len = topics.len();
-		// obtains all params info
-		let topic_params = self.indexed_params(true);
-		let data_params = self.indexed_params(false);
-		// then take first topic if event is not anonymous
+		// take first topic if event is not anonymous
 		let to_skip = if self.anonymous {
 			0
 		} else {
 			// verify
-			let event_signature = topics.get(0).ok_or(Error::InvalidData)?;
-			if event_signature != &self.signature() {
+			let event_signature = topics.first().ok_or(Error::Other(Cow::Borrowed(
+				"non-anonymous event requires at least 1 topic (the signature) but log had 0",
+			)))?;
+			if check_signature && event_signature != &self.signature {
 				return Err(Error::InvalidData);
 			}
 			1
 		};
 
-		let topic_types =
-			topic_params.iter().map(|p| self.convert_topic_param_type(&p.kind)).collect::<Vec<ParamType>>();
+		// every indexed param is a single 32 bytes topic
+		let expected = self.topic_types.len() + to_skip;
+		if topics_len != expected {
+			let indexed = self.topic_types.len();
+			let layout =
+				if self.anonymous { format!("{indexed} indexed") } else { format!("signature and {indexed} indexed") };
+			return Err(Error::Other(Cow::Owned(format!(
+				"wrong number of topics: expected {expected} ({layout}), got {topics_len}"
+			))));
+		}
 
 		let flat_topics = topics.into_iter().skip(to_skip).flat_map(|t| t.as_ref().to_vec()).collect::<Vec<u8>>();
 
-		let topic_tokens = decode(&topic_types, &flat_topics)?;
-
-		// topic may be only a 32 bytes encoded token
-		if topic_tokens.len() != topics_len - to_skip {
-			return Err(Error::InvalidData);
-		}
-
-		let topics_named_tokens = topic_params.into_iter().map(|p| p.name).zip(topic_tokens.into_iter());
+		let topic_tokens = decode(&self.topic_types, &flat_topics)?;
 
-		let data_types = data_params.iter().map(|p| p.kind.clone()).collect::<Vec<ParamType>>();
+		let topics_named_tokens = self.topic_names.iter().cloned().zip(topic_tokens.into_iter());
 
-		let data_tokens = decode(&data_types, &data)?;
+		let data_tokens = decode(&self.data_types, &data)?;
 
-		let data_named_tokens = data_params.into_iter().map(|p| p.name).zip(data_tokens.into_iter());
+		let data_named_tokens = self.data_names.iter().cloned().zip(data_tokens.into_iter());
 
 		let named_tokens = topics_named_tokens.chain(data_named_tokens).collect::<BTreeMap<String, Token>>();
 
-		let decoded_params = self
-			.params_names()
-			.into_iter()
-			.map(|name| LogParam { name: name.clone(), value: named_tokens[&name].clone() })
-			.collect();
+		let decoded_params =
+			self.names.iter().map(|name| LogParam { name: name.clone(), value: named_tokens[name].clone() }).collect();
 
 		let result = Log { params: decoded_params };
 
 		Ok(result)
 	}
-
-	/// Parses `RawLog` and retrieves all log params from it.
-	/// Checks, that decoded data is exact as input provided
-	pub fn parse_log_validate(&self, log: RawLog) -> Result<Log> {
-		self.parse_log_inner(log, decode_validate)
-	}
-
-	/// Parses `RawLog` and retrieves all log params from it.
-	pub fn parse_log(&self, log: RawLog) -> Result<Log> {
-		self.parse_log_inner(log, decode)
-	}
 }
 
 #[cfg(test)]
@@ -198,7 +310,7 @@ mod tests {
 		log::{Log, RawLog},
 		signature::long_signature,
 		token::Token,
-		Event, EventParam, LogParam, ParamType,
+		Error, Event, EventParam, LogParam, ParamType, RawTopicFilter, Topic, TopicFilter,
 	};
 
 	#[test]
@@ -206,20 +318,22 @@ mod tests {
 		let event = Event {
 			name: "foo".to_owned(),
 			inputs: vec![
-				EventParam { name: "a".to_owned(), kind: ParamType::Int(256), indexed: false },
-				EventParam { name: "b".to_owned(), kind: ParamType::Int(256), indexed: true },
-				EventParam { name: "c".to_owned(), kind: ParamType::Address, indexed: false },
-				EventParam { name: "d".to_owned(), kind: ParamType::Address, indexed: true },
-				EventParam { name: "e".to_owned(), kind: ParamType::String, indexed: true },
+				EventParam { name: "a".to_owned(), kind: ParamType::Int(256), indexed: false, components: vec![] },
+				EventParam { name: "b".to_owned(), kind: ParamType::Int(256), indexed: true, components: vec![] },
+				EventParam { name: "c".to_owned(), kind: ParamType::Address, indexed: false, components: vec![] },
+				EventParam { name: "d".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] },
+				EventParam { name: "e".to_owned(), kind: ParamType::String, indexed: true, components: vec![] },
 				EventParam {
 					name: "f".to_owned(),
 					kind: ParamType::Array(Box::new(ParamType::Int(256))),
 					indexed: true,
+					components: vec![],
 				},
 				EventParam {
 					name: "g".to_owned(),
 					kind: ParamType::FixedArray(Box::new(ParamType::Address), 5),
 					indexed: true,
+					components: vec![],
 				},
 			],
 			anonymous: false,
@@ -253,6 +367,10 @@ mod tests {
 			)
 			.into(),
 		};
+		let map = event.parse_log_map(log.clone()).unwrap();
+		assert_eq!(map.len(), 7);
+		assert_eq!(map["c"], Token::Address(hex!("2222222222222222222222222222222222222222").into()));
+
 		let result = event.parse_log(log).unwrap();
 
 		assert_eq!(
@@ -299,8 +417,9 @@ mod tests {
 					name: "tuple".into(),
 					kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
 					indexed: false,
+					components: vec![],
 				},
-				EventParam { name: "addr".into(), kind: ParamType::Address, indexed: true },
+				EventParam { name: "addr".into(), kind: ParamType::Address, indexed: true, components: vec![] },
 			],
 			anonymous: false,
 		};
@@ -327,4 +446,242 @@ mod tests {
 		assert!(wrong_event.parse_log_validate(log.clone()).is_err());
 		assert!(correct_event.parse_log_validate(log).is_ok());
 	}
+
+	#[test]
+	fn parse_log_unchecked() {
+		let event = Event {
+			name: "Test".into(),
+			inputs: vec![
+				EventParam { name: "a".into(), kind: ParamType::Address, indexed: true, components: vec![] },
+				EventParam { name: "b".into(), kind: ParamType::Uint(256), indexed: false, components: vec![] },
+			],
+			anonymous: false,
+		};
+
+		let log = RawLog {
+			topics: vec![
+				hex!("1111111111111111111111111111111111111111111111111111111111111111").into(),
+				hex!("0000000000000000000000002222222222222222222222222222222222222222").into(),
+			],
+			data: hex!("0000000000000000000000000000000000000000000000000000000000000003").into(),
+		};
+
+		assert!(event.parse_log(log.clone()).is_err());
+		assert_eq!(
+			event.parse_log_unchecked(log).unwrap(),
+			Log {
+				params: vec![
+					LogParam { name: "a".into(), value: Token::Address([0x22u8; 20].into()) },
+					LogParam { name: "b".into(), value: Token::Uint(3.into()) },
+				]
+			}
+		);
+		assert!(event.parse_log_unchecked(RawLog { topics: vec![], data: vec![] }).is_err());
+	}
+
+	#[test]
+	fn parse_log_indexed_array() {
+		let event = Event {
+			name: "E".into(),
+			inputs: vec![
+				EventParam {
+					name: "a".into(),
+					kind: ParamType::FixedArray(Box::new(ParamType::Address), 2),
+					indexed: true,
+					components: vec![],
+				},
+				EventParam { name: "b".into(), kind: ParamType::Uint(256), indexed: false, components: vec![] },
+			],
+			anonymous: false,
+		};
+
+		// indexed arrays are stored as the hash of their encoding
+		let a = hex!("b2a1b2e1c8c8b2c6e3d8c4e5f0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9");
+		let log = RawLog {
+			topics: vec![event.signature(), a.into()],
+			data: hex!("0000000000000000000000000000000000000000000000000000000000000007").into(),
+		};
+
+		assert_eq!(
+			event.parse_log(log).unwrap(),
+			Log {
+				params: vec![
+					LogParam { name: "a".into(), value: Token::FixedBytes(a.to_vec()) },
+					LogParam { name: "b".into(), value: Token::Uint(7.into()) },
+				]
+			}
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn parse_log_topic_count() {
+		let mut event = Event {
+			name: "Test".into(),
+			inputs: vec![EventParam { name: "a".into(), kind: ParamType::Address, indexed: true, components: vec![] }],
+			anonymous: false,
+		};
+		let topic = |byte| crate::Hash::from([byte; 32]);
+
+		let err = event.parse_log(RawLog { topics: vec![], data: vec![] }).unwrap_err();
+		assert_eq!(err.to_string(), "non-anonymous event requires at least 1 topic (the signature) but log had 0");
+
+		let err = event.parse_log(RawLog { topics: vec![event.signature()], data: vec![] }).unwrap_err();
+		assert_eq!(err.to_string(), "wrong number of topics: expected 2 (signature and 1 indexed), got 1");
+
+		let topics = vec![event.signature(), topic(0), topic(0)];
+		let err = event.parse_log(RawLog { topics, data: vec![] }).unwrap_err();
+		assert_eq!(err.to_string(), "wrong number of topics: expected 2 (signature and 1 indexed), got 3");
+
+		event.anonymous = true;
+		let err = event.parse_log(RawLog { topics: vec![topic(0), topic(0)], data: vec![] }).unwrap_err();
+		assert_eq!(err.to_string(), "wrong number of topics: expected 1 (1 indexed), got 2");
+		assert!(event.parse_log(RawLog { topics: vec![topic(0)], data: vec![] }).is_ok());
+	}
+
+	#[test]
+	fn parse_logs() {
+		let event = Event {
+			name: "Test".into(),
+			inputs: vec![
+				EventParam { name: "a".into(), kind: ParamType::Address, indexed: true, components: vec![] },
+				EventParam { name: "b".into(), kind: ParamType::Uint(256), indexed: false, components: vec![] },
+			],
+			anonymous: false,
+		};
+		let log = |b: u8| RawLog {
+			topics: vec![
+				event.signature(),
+				hex!("0000000000000000000000002222222222222222222222222222222222222222").into(),
+			],
+			data: [[0u8; 31].as_slice(), &[b]].concat(),
+		};
+		let other = RawLog { topics: vec![[0x11u8; 32].into()], data: vec![] };
+
+		let logs = vec![log(1), other, log(2)];
+		let parsed: Vec<_> = event.parse_logs(logs.clone()).collect();
+
+		assert_eq!(parsed.len(), 3);
+		assert_eq!(parsed[0].as_ref().unwrap(), &event.parse_log(logs[0].clone()).unwrap());
+		assert!(parsed[1].is_err());
+		assert_eq!(parsed[2].as_ref().unwrap().params[1].value, Token::Uint(2.into()));
+	}
+
+	#[test]
+	fn anonymous_event_with_four_indexed_params() {
+		let event = Event {
+			name: "Anon".into(),
+			inputs: vec![
+				EventParam { name: "a".into(), kind: ParamType::Address, indexed: true, components: vec![] },
+				EventParam { name: "b".into(), kind: ParamType::Uint(256), indexed: true, components: vec![] },
+				EventParam { name: "c".into(), kind: ParamType::Bool, indexed: true, components: vec![] },
+				EventParam { name: "d".into(), kind: ParamType::Uint(8), indexed: true, components: vec![] },
+			],
+			anonymous: true,
+		};
+
+		let filter = event
+			.filter(RawTopicFilter {
+				topic0: Topic::This(Token::Address([0x11u8; 20].into())),
+				topic1: Topic::Any,
+				topic2: Topic::This(Token::Bool(true)),
+				topic3: Topic::This(Token::Uint(7.into())),
+			})
+			.unwrap();
+		assert_eq!(
+			filter,
+			TopicFilter {
+				topic0: Topic::This(hex!("0000000000000000000000001111111111111111111111111111111111111111").into()),
+				topic1: Topic::Any,
+				topic2: Topic::This(hex!("0000000000000000000000000000000000000000000000000000000000000001").into()),
+				topic3: Topic::This(hex!("0000000000000000000000000000000000000000000000000000000000000007").into()),
+			}
+		);
+
+		let log = RawLog {
+			topics: vec![
+				hex!("0000000000000000000000001111111111111111111111111111111111111111").into(),
+				hex!("0000000000000000000000000000000000000000000000000000000000000002").into(),
+				hex!("0000000000000000000000000000000000000000000000000000000000000001").into(),
+				hex!("0000000000000000000000000000000000000000000000000000000000000007").into(),
+			],
+			data: vec![],
+		};
+		assert_eq!(
+			event.parse_log(log).unwrap(),
+			Log {
+				params: vec![
+					LogParam { name: "a".into(), value: Token::Address([0x11u8; 20].into()) },
+					LogParam { name: "b".into(), value: Token::Uint(2.into()) },
+					LogParam { name: "c".into(), value: Token::Bool(true) },
+					LogParam { name: "d".into(), value: Token::Uint(7.into()) },
+				]
+			}
+		);
+
+		// non-anonymous events use the first topic for their signature
+		let mut named = event;
+		named.anonymous = false;
+		let raw = RawTopicFilter { topic3: Topic::This(Token::Uint(7.into())), ..Default::default() };
+		assert!(named.filter(raw).is_err());
+	}
+
+	#[test]
+	fn validate_indexed_params() {
+		let param =
+			|name: &str| EventParam { name: name.into(), kind: ParamType::Bool, indexed: true, components: vec![] };
+		let mut event =
+			Event { name: "Foo".into(), inputs: vec![param("a"), param("b"), param("c"), param("d")], anonymous: true };
+		assert!(event.validate().is_ok());
+
+		// matched on the variant, as `Error` only implements `Display` with `std`
+		event.anonymous = false;
+		assert!(matches!(
+			event.validate(),
+			Err(Error::Other(msg)) if msg == "event `Foo` has 4 indexed params, at most 3 are allowed"
+		));
+
+		event.anonymous = true;
+		event.inputs.push(param("e"));
+		assert!(event.validate().is_err());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn deserialize_too_many_indexed_params() {
+		let json = |anonymous: bool| {
+			let input = r#"{ "name": "a", "type": "bool", "indexed": true }"#;
+			format!(r#"{{ "name": "Foo", "inputs": [{input}, {input}, {input}, {input}], "anonymous": {anonymous} }}"#)
+		};
+
+		assert!(serde_json::from_str::<Event>(&json(true)).is_ok());
+		let err = serde_json::from_str::<Event>(&json(false)).unwrap_err();
+		assert!(err.to_string().starts_with("event `Foo` has 4 indexed params, at most 3 are allowed"));
+	}
+
+	#[test]
+	fn signature_string() {
+		use sha3::{Digest, Keccak256};
+
+		let event = Event {
+			name: "OrderFilled".into(),
+			inputs: vec![
+				EventParam {
+					name: "orders".into(),
+					kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+						ParamType::Address,
+						ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bool]),
+					]))),
+					indexed: false,
+					components: vec![],
+				},
+				EventParam { name: "id".into(), kind: ParamType::FixedBytes(32), indexed: true, components: vec![] },
+			],
+			anonymous: false,
+		};
+
+		let signature = event.signature_string();
+		assert_eq!(signature, "OrderFilled((address,(uint256,bool))[],bytes32)");
+		assert_eq!(event.signature().as_bytes(), &Keccak256::digest(signature.as_bytes())[..]);
+	}
 }
@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{ParamType, TupleParam};
 
 /// Event param specification.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,16 @@ pub struct EventParam {
 	pub kind: ParamType,
 	/// Indexed flag. If true, param is used to build block bloom.
 	pub indexed: bool,
+	/// Names and internal types of the components of a tuple, or of a tuple
+	/// array element. Empty if they are unknown or if the param is not a tuple.
+	pub components: Vec<TupleParam>,
+}
+
+impl EventParam {
+	/// Creates an event param.
+	pub fn new(name: impl Into<String>, kind: ParamType, indexed: bool) -> Self {
+		EventParam { name: name.into(), kind, indexed, components: vec![] }
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -97,9 +107,9 @@ impl<'a> Visitor<'a> for EventParamVisitor {
 		}
 		let name = name.ok_or_else(|| Error::missing_field("name"))?;
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		crate::param::set_tuple_components(&mut kind, components)?;
+		let components = crate::param::set_tuple_components(&mut kind, components)?;
 		let indexed = indexed.unwrap_or(false);
-		Ok(EventParam { name, kind, indexed })
+		Ok(EventParam { name, kind, indexed, components })
 	}
 }
 
@@ -115,7 +125,7 @@ impl Serialize for EventParam {
 		map.serialize_entry("indexed", &self.indexed)?;
 		if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
 			map.serialize_key("components")?;
-			map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
+			map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple, &self.components))?;
 		}
 		map.end()
 	}
@@ -125,7 +135,10 @@ impl Serialize for EventParam {
 mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{tests::assert_json_eq, EventParam, ParamType};
+	use crate::{
+		tests::{assert_json_eq, component},
+		EventParam, ParamType,
+	};
 
 	#[test]
 	fn event_param_deserialization() {
@@ -137,7 +150,11 @@ mod tests {
 
 		let deserialized: EventParam = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, EventParam { name: "foo".to_owned(), kind: ParamType::Address, indexed: true });
+		assert_eq!(
+			deserialized,
+			EventParam { name: "foo".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] }
+		);
+		assert_eq!(deserialized, EventParam::new("foo", ParamType::Address, true));
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -171,6 +188,14 @@ mod tests {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
 				indexed: true,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(
+						None,
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				],
 			}
 		);
 
@@ -237,6 +262,53 @@ mod tests {
 					ParamType::Uint(256),
 				]),
 				indexed: false,
+				components: vec![
+					component(None, ParamType::Uint(256), vec![]),
+					component(None, ParamType::Address, vec![]),
+					component(
+						None,
+						ParamType::Tuple(vec![ParamType::Address, ParamType::Address]),
+						vec![component(None, ParamType::Address, vec![]), component(None, ParamType::Address, vec![])]
+					),
+					component(None, ParamType::Uint(256), vec![]),
+					component(
+						None,
+						ParamType::Array(Box::new(ParamType::Tuple(vec![
+							ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Bytes]))),
+							ParamType::Array(Box::new(ParamType::Tuple(vec![
+								ParamType::Address,
+								ParamType::Uint(256)
+							]))),
+							ParamType::Uint(256)
+						]))),
+						vec![
+							component(
+								None,
+								ParamType::Array(Box::new(ParamType::Tuple(vec![
+									ParamType::Address,
+									ParamType::Bytes
+								]))),
+								vec![
+									component(None, ParamType::Address, vec![]),
+									component(None, ParamType::Bytes, vec![])
+								]
+							),
+							component(
+								None,
+								ParamType::Array(Box::new(ParamType::Tuple(vec![
+									ParamType::Address,
+									ParamType::Uint(256)
+								]))),
+								vec![
+									component(None, ParamType::Address, vec![]),
+									component(None, ParamType::Uint(256), vec![])
+								]
+							),
+							component(None, ParamType::Uint(256), vec![])
+						]
+					),
+					component(None, ParamType::Uint(256), vec![])
+				],
 			}
 		);
 
@@ -13,10 +13,10 @@ use serde::{Serialize, Serializer};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Hash, Token};
+use crate::{errors, Event, Hash, Token};
 
 /// Raw topic filter.
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq, Default, Clone)]
 pub struct RawTopicFilter {
 	/// Topic.
 	pub topic0: Topic<Token>,
@@ -24,6 +24,20 @@ pub struct RawTopicFilter {
 	pub topic1: Topic<Token>,
 	/// Topic.
 	pub topic2: Topic<Token>,
+	/// Topic. Only anonymous events can have a fourth indexed param, as the
+	/// first topic of other events is their signature.
+	pub topic3: Topic<Token>,
+}
+
+impl RawTopicFilter {
+	/// Converts the filter into a topic filter for the indexed params of the
+	/// given event. Every token, including each of `Topic::OneOf`, is encoded
+	/// as it is stored in the log topics.
+	///
+	/// See [`Event::filter`].
+	pub fn into_hash_filter(&self, event: &Event) -> errors::Result<TopicFilter> {
+		event.filter(self.clone())
+	}
 }
 
 /// Topic filter.
@@ -50,7 +64,7 @@ impl Serialize for TopicFilter {
 }
 
 /// Acceptable topic possibilities.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum Topic<T> {
 	/// Match any.
 	#[default]
@@ -147,13 +161,12 @@ impl<T> ops::Index<usize> for Topic<T> {
 
 #[cfg(test)]
 mod tests {
-	use super::Topic;
 	#[cfg(feature = "serde")]
 	use super::TopicFilter;
+	use super::{RawTopicFilter, Topic};
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	#[cfg(feature = "serde")]
-	use crate::Hash;
+	use crate::{Event, EventParam, Hash, ParamType, Token};
 
 	#[cfg(feature = "serde")]
 	fn hash(s: &'static str) -> Hash {
@@ -179,6 +192,41 @@ mod tests {
 		assert_eq!(expected, &topic_str);
 	}
 
+	#[test]
+	fn test_raw_topic_filter_into_hash_filter() {
+		let event = Event {
+			name: "Transfer".to_owned(),
+			inputs: vec![
+				EventParam::new("from", ParamType::Address, true),
+				EventParam::new("to", ParamType::Address, true),
+				EventParam::new("value", ParamType::Uint(256), false),
+			],
+			anonymous: false,
+		};
+		let address = |byte: u8| Token::Address([byte; 20].into());
+		let topic = |byte: u8| {
+			let mut topic = Hash::zero();
+			topic[12..].copy_from_slice(&[byte; 20]);
+			topic
+		};
+
+		let raw = RawTopicFilter {
+			topic0: Topic::OneOf(vec![address(0x11), address(0x22), address(0x33)]),
+			topic1: Topic::This(address(0x44)),
+			topic2: Topic::Any,
+			topic3: Topic::Any,
+		};
+		let filter = raw.into_hash_filter(&event).unwrap();
+
+		assert_eq!(filter.topic0, Topic::This(event.signature()));
+		assert_eq!(filter.topic1, Topic::OneOf(vec![topic(0x11), topic(0x22), topic(0x33)]));
+		assert_eq!(filter.topic2, Topic::This(topic(0x44)));
+		assert_eq!(filter.topic3, Topic::Any);
+
+		let raw = RawTopicFilter { topic0: Topic::OneOf(vec![Token::Bool(true)]), ..Default::default() };
+		assert!(raw.into_hash_filter(&event).is_err());
+	}
+
 	#[test]
 	fn test_topic_from() {
 		assert_eq!(Topic::Any as Topic<u64>, None.into());
@@ -14,15 +14,28 @@ use serde::{Deserialize, Serialize};
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-	decode, encode, signature::short_signature, Bytes, Error, Param, ParamType, Result, StateMutability, Token,
+	decoder::decode_function_data,
+	encode,
+	no_std_prelude::Cow,
+	signature::{canonical_signature, short_signature},
+	split_selector, Bytes, Error, Param, ParamType, Result, StateMutability, Token,
 };
 
+/// Position of a function argument in the head of the encoded arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgLayout {
+	/// Offset of the argument head from the start of the arguments.
+	pub head_offset: usize,
+	/// Whether the head is an offset pointing to the argument data in the tail.
+	pub is_dynamic: bool,
+}
+
 /// Contract function specification.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "FunctionRepr"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
 	/// Function name.
-	#[cfg_attr(feature = "serde", serde(deserialize_with = "crate::util::sanitize_name::deserialize"))]
 	pub name: String,
 	/// Function input.
 	pub inputs: Vec<Param>,
@@ -38,28 +51,139 @@ pub struct Function {
 	pub state_mutability: StateMutability,
 }
 
+/// Function as found in abi files, including the `constant` and `payable`
+/// attributes of ABIs generated before Solidity 0.5.0.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct FunctionRepr {
+	#[serde(deserialize_with = "crate::util::deserialize_sanitized_name")]
+	name: String,
+	inputs: Vec<Param>,
+	outputs: Vec<Param>,
+	constant: Option<bool>,
+	#[serde(default)]
+	payable: bool,
+	#[serde(rename = "stateMutability")]
+	state_mutability: Option<StateMutability>,
+}
+
+#[cfg(feature = "serde")]
+impl From<FunctionRepr> for Function {
+	fn from(repr: FunctionRepr) -> Self {
+		// `stateMutability` takes precedence over the legacy attributes.
+		let state_mutability = repr.state_mutability.unwrap_or(match (repr.constant, repr.payable) {
+			(Some(true), _) => StateMutability::View,
+			(_, true) => StateMutability::Payable,
+			_ => StateMutability::NonPayable,
+		});
+
+		#[allow(deprecated)]
+		Function {
+			name: repr.name,
+			inputs: repr.inputs,
+			outputs: repr.outputs,
+			constant: repr.constant,
+			state_mutability,
+		}
+	}
+}
+
 impl Function {
-	/// Returns all input params of given function.
-	fn input_param_types(&self) -> Vec<ParamType> {
+	/// Returns the types of all input params of the function.
+	pub fn input_param_types(&self) -> Vec<ParamType> {
 		self.inputs.iter().map(|p| p.kind.clone()).collect()
 	}
 
-	/// Returns all output params of given function.
-	fn output_param_types(&self) -> Vec<ParamType> {
+	/// Returns the types of all output params of the function.
+	pub fn output_param_types(&self) -> Vec<ParamType> {
 		self.outputs.iter().map(|p| p.kind.clone()).collect()
 	}
 
 	/// Prepares ABI function call with given input params.
+	///
+	/// If the function takes a single tuple, its components may also be
+	/// passed directly instead of being wrapped in a `Token::Tuple`.
 	pub fn encode_input(&self, tokens: &[Token]) -> Result<Bytes> {
+		self.encode_input_with_selector(self.short_signature(), tokens)
+	}
+
+	/// Prepares ABI function call with given input params, prefixed with
+	/// `selector` instead of the selector of this function.
+	pub fn encode_input_with_selector(&self, selector: [u8; 4], tokens: &[Token]) -> Result<Bytes> {
+		let encoded = self.encode_args(tokens)?;
+		Ok(selector.into_iter().chain(encoded.into_iter()).collect())
+	}
+
+	/// Encodes the given input params without prefixing them with a selector.
+	///
+	/// The params are checked against the input types like in
+	/// [`encode_input`](Function::encode_input).
+	pub fn encode_args(&self, tokens: &[Token]) -> Result<Bytes> {
 		let params = self.input_param_types();
 
+		let wrapped;
+		let tokens = match params.as_slice() {
+			[ParamType::Tuple(_)] if !Token::types_check(tokens, &params) => {
+				wrapped = [Token::Tuple(tokens.to_vec())];
+				&wrapped[..]
+			}
+			_ => tokens,
+		};
+
+		if tokens.len() != params.len() {
+			return Err(Error::Other(Cow::Owned(format!("expected {} arguments, got {}", params.len(), tokens.len()))));
+		}
 		if !Token::types_check(tokens, &params) {
 			return Err(Error::InvalidData);
 		}
 
-		let signed = short_signature(&self.name, &params).to_vec();
-		let encoded = encode(tokens);
-		Ok(signed.into_iter().chain(encoded.into_iter()).collect())
+		Ok(encode(tokens))
+	}
+
+	/// Builds a `(canonical signature, arguments, calldata)` test vector for a call of
+	/// this function with given input params.
+	///
+	/// The arguments are rendered with the `Display` implementation of `Token`
+	/// and the calldata is hex encoded without a `0x` prefix.
+	pub fn test_vector(&self, tokens: &[Token]) -> Result<(String, String, String)> {
+		let calldata = self.encode_input(tokens)?;
+		let args = tokens.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",");
+		Ok((self.canonical_signature(), format!("({args})"), hex::encode(calldata)))
+	}
+
+	/// Returns the JSON fragment describing this function in a contract ABI.
+	#[cfg(feature = "full-serde")]
+	pub fn to_json_value(&self) -> serde_json::Value {
+		crate::operation::OperationRef::Function(self).to_json_value()
+	}
+
+	/// Returns true if the function does not modify blockchain state, that is
+	/// if it is `view` or `pure`. Such functions are called with `eth_call`.
+	pub fn is_read_only(&self) -> bool {
+		matches!(self.state_mutability, StateMutability::View | StateMutability::Pure)
+	}
+
+	/// Returns true if the function accepts ether.
+	pub fn is_payable(&self) -> bool {
+		self.state_mutability == StateMutability::Payable
+	}
+
+	/// Returns the head layout of the encoded inputs of this function.
+	///
+	/// Offsets are relative to the start of the arguments, after the selector.
+	/// Dynamic inputs are stored in the tail and their head only contains the
+	/// offset of their data.
+	pub fn input_layout(&self) -> Vec<ArgLayout> {
+		let mut head_offset = 0;
+		self.inputs
+			.iter()
+			.map(|param| {
+				let layout = ArgLayout { head_offset, is_dynamic: param.kind.is_dynamic() };
+				head_offset =
+					param.kind.head_size().and_then(|size| head_offset.checked_add(size)).unwrap_or(usize::MAX);
+				layout
+			})
+			.collect()
 	}
 
 	/// Return the 4 byte short signature of this function.
@@ -68,18 +192,62 @@ impl Function {
 		short_signature(&self.name, &params)
 	}
 
+	/// Returns whether `selector` is the 4 byte short signature of this function.
+	pub fn matches_selector(&self, selector: [u8; 4]) -> bool {
+		self.short_signature() == selector
+	}
+
 	/// Parses the ABI function output to list of tokens.
+	///
+	/// Fails with a message giving the expected minimum length if the data is
+	/// too short to contain the heads of all outputs and the start of their tails.
 	pub fn decode_output(&self, data: &[u8]) -> Result<Vec<Token>> {
-		decode(&self.output_param_types(), data)
+		let types = self.output_param_types();
+		decode_function_data("output", &types, data)
 	}
 
 	/// Parses the ABI function input to a list of tokens.
+	///
+	/// Fails with a message giving the expected minimum length if the data is
+	/// too short to contain the heads of all inputs and the start of their tails.
 	pub fn decode_input(&self, data: &[u8]) -> Result<Vec<Token>> {
-		decode(&self.input_param_types(), data)
+		let types = self.input_param_types();
+		decode_function_data("input", &types, data)
+	}
+
+	/// Parses full call data, including the 4 byte selector, to a list of tokens.
+	///
+	/// Fails if the call data does not start with the selector of this function.
+	pub fn decode_input_with_selector(&self, calldata: &[u8]) -> Result<Vec<Token>> {
+		let (selector, data) = split_selector(calldata, "call data")?;
+		let expected = self.short_signature();
+		if selector != expected {
+			return Err(Error::Other(Cow::Owned(format!(
+				"selector mismatch: expected 0x{}, got 0x{}",
+				hex::encode(expected),
+				hex::encode(selector)
+			))));
+		}
+
+		self.decode_input(data)
+	}
+
+	/// Returns the canonical signature of this function, which is hashed to
+	/// compute its selector. Tuples are expanded into their component types.
+	///
+	/// Examples:
+	/// - `functionName()`
+	/// - `transfer(address,uint256)`
+	/// - `submit((address,uint256)[],bytes)`
+	pub fn canonical_signature(&self) -> String {
+		canonical_signature(&self.name, self.inputs.iter().map(|p| &p.kind))
 	}
 
 	/// Returns a signature that uniquely identifies this function.
 	///
+	/// Unlike [`Function::canonical_signature`], this includes the outputs and
+	/// is therefore **not** the string hashed to compute the selector.
+	///
 	/// Examples:
 	/// - `functionName()`
 	/// - `functionName():(uint256)`
@@ -103,7 +271,7 @@ mod tests {
 
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{Function, Param, ParamType, StateMutability, Token};
+	use crate::{ArgLayout, Function, Param, ParamType, StateMutability, Token};
 
 	#[test]
 	fn test_function_encode_call() {
@@ -111,8 +279,8 @@ mod tests {
 		let func = Function {
 			name: "baz".to_owned(),
 			inputs: vec![
-				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None },
-				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None },
+				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None, components: vec![] },
 			],
 			outputs: vec![],
 			constant: None,
@@ -128,4 +296,244 @@ mod tests {
 		let expected_sig = hex!("cdcd77c0").to_vec();
 		assert_eq!(func.short_signature().to_vec(), expected_sig);
 	}
+
+	#[test]
+	fn test_function_encode_args() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "baz".to_owned(),
+			inputs: vec![
+				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None, components: vec![] },
+			],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::Payable,
+		};
+		let tokens = [Token::Uint(69.into()), Token::Bool(true)];
+
+		let args = func.encode_args(&tokens).unwrap();
+		let expected = hex!("00000000000000000000000000000000000000000000000000000000000000450000000000000000000000000000000000000000000000000000000000000001");
+		assert_eq!(args, expected);
+
+		let encoded = func.encode_input_with_selector([0xaa, 0xbb, 0xcc, 0xdd], &tokens).unwrap();
+		assert_eq!(encoded[..4], hex!("aabbccdd"));
+		assert_eq!(encoded[4..], expected);
+
+		assert!(func.encode_args(&[Token::Bool(true)]).is_err());
+		assert!(func.encode_input_with_selector([0; 4], &[Token::Bool(true)]).is_err());
+	}
+
+	#[test]
+	fn test_function_test_vector() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "baz".to_owned(),
+			inputs: vec![
+				Param { name: "a".to_owned(), kind: ParamType::Uint(32), internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::Bool, internal_type: None, components: vec![] },
+			],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::Payable,
+		};
+
+		let tokens = [Token::Uint(69.into()), Token::Bool(true)];
+		let (signature, args, calldata) = func.test_vector(&tokens).unwrap();
+		assert_eq!(signature, "baz(uint32,bool)");
+		assert_eq!(args, "(69,true)");
+		assert_eq!(calldata, "cdcd77c000000000000000000000000000000000000000000000000000000000000000450000000000000000000000000000000000000000000000000000000000000001");
+
+		let calldata = hex::decode(calldata).unwrap();
+		assert_eq!(calldata[..4], func.short_signature());
+		assert_eq!(func.decode_input(&calldata[4..]).unwrap(), tokens);
+	}
+
+	#[test]
+	fn test_function_encode_call_single_tuple() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "submit".to_owned(),
+			inputs: vec![Param {
+				name: "order".to_owned(),
+				kind: ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]),
+				internal_type: None,
+				components: vec![],
+			}],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		let components = vec![Token::Address([0x11u8; 20].into()), Token::Uint(69.into())];
+		let wrapped = func.encode_input(&[Token::Tuple(components.clone())]).unwrap();
+		let flattened = func.encode_input(&components).unwrap();
+		assert_eq!(wrapped, flattened);
+
+		assert!(func.encode_input(&components[..1]).is_err());
+		assert!(func.encode_input(&[components[0].clone(), components[1].clone(), Token::Bool(true)]).is_err());
+	}
+
+	#[test]
+	fn test_function_canonical_signature() {
+		use sha3::{Digest, Keccak256};
+
+		#[allow(deprecated)]
+		let func = Function {
+			name: "submit".to_owned(),
+			inputs: vec![
+				Param {
+					name: "orders".to_owned(),
+					kind: ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]))),
+					internal_type: None,
+					components: vec![],
+				},
+				Param { name: "data".to_owned(), kind: ParamType::Bytes, internal_type: None, components: vec![] },
+			],
+			outputs: vec![Param {
+				name: "".to_owned(),
+				kind: ParamType::Bool,
+				internal_type: None,
+				components: vec![],
+			}],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		assert_eq!(func.canonical_signature(), "submit((address,uint256)[],bytes)");
+		assert_eq!(func.signature(), "submit((address,uint256)[],bytes):(bool)");
+		assert_eq!(func.short_signature(), Keccak256::digest(func.canonical_signature().as_bytes())[..4]);
+	}
+
+	#[test]
+	fn test_function_state_mutability_predicates() {
+		let function = |state_mutability| {
+			#[allow(deprecated)]
+			Function { name: "f".to_owned(), inputs: vec![], outputs: vec![], constant: None, state_mutability }
+		};
+
+		assert!(function(StateMutability::Pure).is_read_only());
+		assert!(function(StateMutability::View).is_read_only());
+		assert!(!function(StateMutability::NonPayable).is_read_only());
+		assert!(!function(StateMutability::Payable).is_read_only());
+
+		assert!(function(StateMutability::Payable).is_payable());
+		assert!(!function(StateMutability::NonPayable).is_payable());
+		assert!(!function(StateMutability::View).is_payable());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_function_decode_too_short() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "f".to_owned(),
+			inputs: vec![Param::new("a", ParamType::Uint(256)), Param::new("b", ParamType::Bytes)],
+			outputs: vec![Param::new("", ParamType::Bool)],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		let data = func.encode_input(&[Token::Uint(1.into()), Token::Bytes(vec![0x12])]).unwrap();
+		let args = &data[4..];
+		assert_eq!(func.decode_input(args).unwrap(), vec![Token::Uint(1.into()), Token::Bytes(vec![0x12])]);
+
+		let err = func.decode_input(&args[..32]).unwrap_err();
+		assert_eq!(err.to_string(), "input too short: expected at least 64 bytes, got 32");
+		let err = func.decode_input(&args[..64]).unwrap_err();
+		assert_eq!(err.to_string(), "input too short: expected at least 96 bytes, got 64");
+		let err = func.decode_output(&[0u8; 16]).unwrap_err();
+		assert_eq!(err.to_string(), "output too short: expected at least 32 bytes, got 16");
+		assert!(matches!(func.decode_output(&[]), Err(crate::Error::InvalidName(_))));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_function_encode_wrong_argument_count() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "baz".to_owned(),
+			inputs: vec![Param::new("a", ParamType::Uint(32)), Param::new("b", ParamType::Bool)],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::Payable,
+		};
+
+		let err = func.encode_input(&[Token::Uint(69.into())]).unwrap_err();
+		assert_eq!(err.to_string(), "expected 2 arguments, got 1");
+		let err = func.encode_args(&[Token::Uint(69.into()), Token::Bool(true), Token::Bool(false)]).unwrap_err();
+		assert_eq!(err.to_string(), "expected 2 arguments, got 3");
+		let err = func.encode_input(&[Token::Bool(true), Token::Bool(true)]).unwrap_err();
+		assert_eq!(err.to_string(), "Invalid data");
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_function_decode_input_with_selector() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "baz".to_owned(),
+			inputs: vec![Param::new("a", ParamType::Uint(32)), Param::new("b", ParamType::Bool)],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::Payable,
+		};
+
+		let tokens = vec![Token::Uint(69.into()), Token::Bool(true)];
+		let calldata = func.encode_input(&tokens).unwrap();
+		assert_eq!(func.decode_input_with_selector(&calldata).unwrap(), tokens);
+
+		assert!(func.matches_selector([0xcd, 0xcd, 0x77, 0xc0]));
+		assert!(!func.matches_selector([0; 4]));
+
+		let mut wrong = calldata.clone();
+		wrong[0] = 0;
+		let err = func.decode_input_with_selector(&wrong).unwrap_err();
+		assert_eq!(err.to_string(), "selector mismatch: expected 0xcdcd77c0, got 0x00cd77c0");
+
+		let err = func.decode_input_with_selector(&calldata[..3]).unwrap_err();
+		assert_eq!(err.to_string(), "call data too short: expected at least 4 bytes, got 3");
+	}
+
+	#[test]
+	fn test_function_input_layout() {
+		#[allow(deprecated)]
+		let func = Function {
+			name: "f".to_owned(),
+			inputs: vec![
+				Param { name: "a".to_owned(), kind: ParamType::Uint(256), internal_type: None, components: vec![] },
+				Param { name: "b".to_owned(), kind: ParamType::Bytes, internal_type: None, components: vec![] },
+				Param {
+					name: "c".to_owned(),
+					kind: ParamType::FixedArray(
+						Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Bool])),
+						2,
+					),
+					internal_type: None,
+					components: vec![],
+				},
+				Param {
+					name: "d".to_owned(),
+					kind: ParamType::Tuple(vec![ParamType::Address, ParamType::String]),
+					internal_type: None,
+					components: vec![],
+				},
+				Param { name: "e".to_owned(), kind: ParamType::Bool, internal_type: None, components: vec![] },
+			],
+			outputs: vec![],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+
+		assert_eq!(
+			func.input_layout(),
+			vec![
+				ArgLayout { head_offset: 0, is_dynamic: false },
+				ArgLayout { head_offset: 32, is_dynamic: true },
+				ArgLayout { head_offset: 64, is_dynamic: false },
+				ArgLayout { head_offset: 192, is_dynamic: true },
+				ArgLayout { head_offset: 224, is_dynamic: false },
+			]
+		);
+	}
 }
@@ -0,0 +1,330 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of single functions and events from human-readable signatures.
+
+use core::convert::TryFrom;
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+	no_std_prelude::Cow,
+	param_type::{closing_paren, split_top_level, Reader},
+	Error, Event, EventParam, Function, Param, StateMutability,
+};
+
+/// Parses a function from a signature such as
+/// `function transfer(address to, uint256 amount) external returns (bool)`.
+///
+/// The `function` keyword, param names, data locations, visibility and
+/// `returns` are optional. The state mutability is taken from `view`, `pure`
+/// or `payable`, and is `nonpayable` otherwise. Names of tuple components
+/// are accepted but not kept.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use ethabi::{Function, StateMutability};
+///
+/// let function = Function::try_from("balanceOf(address owner) view returns (uint256)").unwrap();
+/// assert_eq!(function.signature(), "balanceOf(address):(uint256)");
+/// assert_eq!(function.inputs[0].name, "owner");
+/// assert_eq!(function.state_mutability, StateMutability::View);
+/// ```
+impl TryFrom<&str> for Function {
+	type Error = Error;
+
+	fn try_from(signature: &str) -> Result<Self, Error> {
+		let invalid = |reason: String| invalid_signature("function", signature, reason);
+		let (name, params, rest) = split_signature(signature, "function").map_err(invalid)?;
+		let inputs = read_params(params, false).map_err(invalid)?;
+
+		let mut state_mutability = StateMutability::NonPayable;
+		let mut outputs = vec![];
+		let mut rest = rest.trim_start();
+		while !rest.is_empty() {
+			let (word, tail) = rest.split_at(rest.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(rest.len()));
+			match word {
+				"returns" => {
+					let (params, tail) = take_parenthesized(tail.trim_start())
+						.ok_or_else(|| invalid("expected `(` after `returns`".into()))?
+						.map_err(invalid)?;
+					outputs = read_params(params, false).map_err(invalid)?;
+					if !tail.trim().is_empty() {
+						return Err(invalid(format!("unexpected `{}` after outputs", tail.trim())));
+					}
+					rest = "";
+					continue;
+				}
+				"view" | "constant" => state_mutability = StateMutability::View,
+				"pure" => state_mutability = StateMutability::Pure,
+				"payable" => state_mutability = StateMutability::Payable,
+				"nonpayable" | "external" | "public" | "internal" | "private" | "virtual" | "override" => (),
+				_ => return Err(invalid(format!("unexpected `{word}`"))),
+			}
+			rest = tail.trim_start();
+		}
+
+		#[allow(deprecated)]
+		Ok(Function {
+			name: name.to_owned(),
+			inputs: inputs.into_iter().map(|(name, kind, _)| Param::new(name, kind)).collect(),
+			outputs: outputs.into_iter().map(|(name, kind, _)| Param::new(name, kind)).collect(),
+			constant: None,
+			state_mutability,
+		})
+	}
+}
+
+/// Parses an event from a signature such as
+/// `event Transfer(address indexed from, address indexed to, uint256 value)`.
+///
+/// The `event` keyword and param names are optional, a trailing `anonymous`
+/// marks the event as anonymous. Names of tuple components are accepted but
+/// not kept.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use ethabi::Event;
+///
+/// let event = Event::try_from("Transfer(address indexed,address indexed,uint256)").unwrap();
+/// assert_eq!(event.signature_string(), "Transfer(address,address,uint256)");
+/// assert!(event.inputs[0].indexed && !event.inputs[2].indexed);
+/// ```
+impl TryFrom<&str> for Event {
+	type Error = Error;
+
+	fn try_from(signature: &str) -> Result<Self, Error> {
+		let invalid = |reason: String| invalid_signature("event", signature, reason);
+		let (name, params, rest) = split_signature(signature, "event").map_err(invalid)?;
+		let inputs = read_params(params, true).map_err(invalid)?;
+
+		let anonymous = match rest.trim() {
+			"" => false,
+			"anonymous" => true,
+			rest => return Err(invalid(format!("unexpected `{rest}`"))),
+		};
+
+		let event = Event {
+			name: name.to_owned(),
+			inputs: inputs
+				.into_iter()
+				.map(|(name, kind, indexed)| EventParam { name, kind, indexed, components: vec![] })
+				.collect(),
+			anonymous,
+		};
+		event.validate()?;
+		Ok(event)
+	}
+}
+
+fn invalid_signature(what: &str, signature: &str, reason: String) -> Error {
+	Error::Other(Cow::Owned(format!("invalid {what} signature `{signature}`: {reason}")))
+}
+
+/// Splits a signature into its name, the contents of its parameter list and
+/// whatever follows the parameter list.
+fn split_signature<'a>(signature: &'a str, keyword: &str) -> Result<(&'a str, &'a str, &'a str), String> {
+	let signature = signature.trim();
+	let signature = match signature.strip_prefix(keyword) {
+		Some(rest) if rest.starts_with(char::is_whitespace) => rest.trim_start(),
+		_ => signature,
+	};
+
+	let params_start = signature.find('(').ok_or("missing `(`")?;
+	let name = signature[..params_start].trim_end();
+	if !is_identifier(name) {
+		return Err(match name {
+			"" => "missing name".into(),
+			name => format!("invalid name `{name}`"),
+		});
+	}
+
+	let (params, rest) = take_parenthesized(&signature[params_start..]).expect("starts with `(`; qed")?;
+	Ok((name, params, rest))
+}
+
+/// Returns the contents of the parentheses `s` starts with and the rest of `s`,
+/// or `None` if `s` doesn't start with `(`.
+fn take_parenthesized(s: &str) -> Option<Result<(&str, &str), String>> {
+	if !s.starts_with('(') {
+		return None;
+	}
+	Some(closing_paren(s).map(|end| (&s[1..end], &s[end + 1..])).ok_or_else(|| "unbalanced parentheses".into()))
+}
+
+/// Reads a comma separated list of params, each a type followed by optional
+/// modifiers and an optional name.
+fn read_params(params: &str, event: bool) -> Result<Vec<(String, crate::ParamType, bool)>, String> {
+	if params.trim().is_empty() {
+		return Ok(vec![]);
+	}
+
+	split_top_level(params, |c| c == ',')
+		.into_iter()
+		.map(|param| {
+			let mut words = split_top_level(param, char::is_whitespace).into_iter().filter(|word| !word.is_empty());
+			let ty = words.next().ok_or("empty param")?;
+			let kind = Reader::read(&canonical_type(ty)?).map_err(|_| format!("invalid type `{ty}`"))?;
+
+			let mut indexed = false;
+			let mut name = None;
+			for word in words {
+				match word {
+					_ if name.is_some() => return Err(format!("unexpected `{word}` after param name")),
+					"indexed" if event => indexed = true,
+					"memory" | "calldata" | "storage" if !event => (),
+					"indexed" => return Err("`indexed` is only allowed in event params".into()),
+					_ if is_identifier(word) => name = Some(word.to_owned()),
+					_ => return Err(format!("invalid param name `{word}`")),
+				}
+			}
+
+			Ok((name.unwrap_or_default(), kind, indexed))
+		})
+		.collect()
+}
+
+/// Converts a type, which may be a tuple with named components and may be
+/// written with the `tuple` keyword, to the form understood by [`Reader`].
+fn canonical_type(ty: &str) -> Result<String, String> {
+	let ty = ty.strip_prefix("tuple").filter(|rest| rest.starts_with('(')).unwrap_or(ty);
+	let (components, suffix) = match take_parenthesized(ty) {
+		Some(parts) => parts?,
+		None => return Ok(ty.to_owned()),
+	};
+
+	let components = match components.trim() {
+		"" => vec![],
+		components => split_top_level(components, |c| c == ',')
+			.into_iter()
+			.map(|component| {
+				let mut words = split_top_level(component, char::is_whitespace).into_iter().filter(|w| !w.is_empty());
+				let ty = words.next().ok_or("empty tuple component")?;
+				match (words.next(), words.next()) {
+					(None, _) => (),
+					(Some(name), None) if is_identifier(name) => (),
+					_ => return Err(format!("invalid tuple component `{}`", component.trim())),
+				}
+				canonical_type(ty)
+			})
+			.collect::<Result<_, _>>()?,
+	};
+	Ok(format!("({}){suffix}", components.join(",")))
+}
+
+fn is_identifier(name: &str) -> bool {
+	!name.is_empty()
+		&& !name.starts_with(|c: char| c.is_ascii_digit())
+		&& name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+	use core::convert::TryFrom;
+
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{Event, EventParam, Function, Param, ParamType, StateMutability};
+
+	#[test]
+	fn function_try_from() {
+		let function = Function::try_from("transfer(address,uint256) returns (bool)").unwrap();
+		#[allow(deprecated)]
+		let expected = Function {
+			name: "transfer".to_owned(),
+			inputs: vec![Param::new("", ParamType::Address), Param::new("", ParamType::Uint(256))],
+			outputs: vec![Param::new("", ParamType::Bool)],
+			constant: None,
+			state_mutability: StateMutability::NonPayable,
+		};
+		assert_eq!(function, expected);
+
+		let function = Function::try_from(
+			"function submit((address target, bytes data)[] calldata calls, uint value) external payable returns (bool ok)",
+		)
+		.unwrap();
+		assert_eq!(function.signature(), "submit((address,bytes)[],uint256):(bool)");
+		assert_eq!(function.inputs[0].name, "calls");
+		assert_eq!(function.outputs[0].name, "ok");
+		assert_eq!(function.state_mutability, StateMutability::Payable);
+
+		let function = Function::try_from("f(tuple(uint8,bool)[2] memory a) pure").unwrap();
+		assert_eq!(function.signature(), "f((uint8,bool)[2])");
+		assert_eq!(function.state_mutability, StateMutability::Pure);
+		assert_eq!(Function::try_from("f()").unwrap().signature(), "f()");
+	}
+
+	#[test]
+	fn event_try_from() {
+		let event = Event::try_from("Transfer(address indexed,address indexed,uint256)").unwrap();
+		let param = |name: &str, kind, indexed| EventParam { name: name.to_owned(), kind, indexed, components: vec![] };
+		assert_eq!(
+			event,
+			Event {
+				name: "Transfer".to_owned(),
+				inputs: vec![
+					param("", ParamType::Address, true),
+					param("", ParamType::Address, true),
+					param("", ParamType::Uint(256), false)
+				],
+				anonymous: false,
+			}
+		);
+
+		let event = Event::try_from("event Filled(bytes32 indexed id, (address maker, uint amount)[] fills) anonymous")
+			.unwrap();
+		assert_eq!(event.signature_string(), "Filled(bytes32,(address,uint256)[])");
+		assert_eq!(event.inputs[0], param("id", ParamType::FixedBytes(32), true));
+		assert_eq!(event.inputs[1].name, "fills");
+		assert!(event.anonymous);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn try_from_errors() {
+		let function_error = |signature: &str| Function::try_from(signature).unwrap_err().to_string();
+		let event_error = |signature: &str| Event::try_from(signature).unwrap_err().to_string();
+
+		assert_eq!(function_error("transfer"), "invalid function signature `transfer`: missing `(`");
+		assert_eq!(function_error("(address)"), "invalid function signature `(address)`: missing name");
+		assert_eq!(function_error("1f()"), "invalid function signature `1f()`: invalid name `1f`");
+		assert_eq!(function_error("f(uint256"), "invalid function signature `f(uint256`: unbalanced parentheses");
+		assert_eq!(function_error("f(uint7)"), "invalid function signature `f(uint7)`: invalid type `uint7`");
+		assert_eq!(function_error("f(uint256,)"), "invalid function signature `f(uint256,)`: empty param");
+		assert_eq!(
+			function_error("f(address indexed a)"),
+			"invalid function signature `f(address indexed a)`: `indexed` is only allowed in event params"
+		);
+		assert_eq!(
+			function_error("f() returns bool"),
+			"invalid function signature `f() returns bool`: expected `(` after `returns`"
+		);
+		assert_eq!(function_error("f() view view2"), "invalid function signature `f() view view2`: unexpected `view2`");
+		assert_eq!(
+			function_error("f() returns (bool) view"),
+			"invalid function signature `f() returns (bool) view`: unexpected `view` after outputs"
+		);
+		assert_eq!(
+			event_error("E(uint256 a b)"),
+			"invalid event signature `E(uint256 a b)`: unexpected `b` after param name"
+		);
+		assert_eq!(
+			event_error("E(uint256 a) returns (bool)"),
+			"invalid event signature `E(uint256 a) returns (bool)`: unexpected `returns (bool)`"
+		);
+		assert_eq!(
+			event_error("E((uint256 a b))"),
+			"invalid event signature `E((uint256 a b))`: invalid tuple component `uint256 a b`"
+		);
+		assert_eq!(
+			event_error("Foo(bool indexed,bool indexed,bool indexed,bool indexed)"),
+			"event `Foo` has 4 indexed params, at most 3 are allowed"
+		);
+		assert!(Event::try_from("Foo(bool indexed,bool indexed,bool indexed,bool indexed) anonymous").is_ok());
+	}
+}
@@ -30,6 +30,9 @@ mod no_std_prelude {
 #[cfg(not(feature = "std"))]
 use no_std_prelude::*;
 
+#[cfg(feature = "serde")]
+mod artifact;
+mod checksum;
 mod constructor;
 mod contract;
 mod decoder;
@@ -40,15 +43,20 @@ mod event;
 mod event_param;
 mod filter;
 mod function;
+mod human_readable;
 mod log;
+mod multicall;
 #[cfg(feature = "serde")]
 mod operation;
 mod param;
 pub mod param_type;
+#[cfg(feature = "presets")]
+pub mod presets;
 mod signature;
 mod state_mutability;
+#[cfg(feature = "test-helpers")]
+pub mod testing;
 pub mod token;
-#[cfg(feature = "serde")]
 mod tuple_param;
 mod util;
 
@@ -58,24 +66,28 @@ mod tests;
 pub use ethereum_types;
 
 #[cfg(feature = "serde")]
+pub use crate::artifact::Artifact;
 pub use crate::tuple_param::TupleParam;
 pub use crate::{
+	checksum::{checksum_address, has_valid_checksum},
 	constructor::Constructor,
-	contract::{Contract, Events, Functions},
-	decoder::{decode, decode_validate},
-	encoder::encode,
+	contract::{Contract, ContractBuilder, ContractDiff, EntryDiff, Events, Functions, PreparedContract},
+	decoder::{decode, decode_lenient, decode_limited, decode_validate, DecodeLimits},
+	encoder::{encode, encode_topic, encode_words, encoded_size},
 	error::Error as AbiError,
 	errors::{Error, Result},
 	event::Event,
 	event_param::EventParam,
 	filter::{RawTopicFilter, Topic, TopicFilter},
-	function::Function,
+	function::{ArgLayout, Function},
 	log::{Log, LogFilter, LogParam, ParseLog, RawLog},
-	param::Param,
+	multicall::{encode_multicall, encode_multicall3},
+	param::{InternalTypeKind, Param},
 	param_type::ParamType,
 	signature::{long_signature, short_signature},
 	state_mutability::StateMutability,
 	token::Token,
+	util::{sanitize_name, split_selector},
 };
 
 /// ABI word.
@@ -62,3 +62,30 @@ pub struct Log {
 	/// Log params.
 	pub params: Vec<LogParam>,
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use crate::{Address, Log, LogParam, Token};
+
+	#[test]
+	fn log_serde() {
+		let log = Log {
+			params: vec![
+				LogParam { name: "from".into(), value: Token::Address(Address::from([0x11u8; 20])) },
+				LogParam { name: "value".into(), value: Token::Uint(100.into()) },
+			],
+		};
+
+		let json = serde_json::to_value(&log).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({
+				"params": [
+					{ "name": "from", "value": { "Address": "0x1111111111111111111111111111111111111111" } },
+					{ "name": "value", "value": { "Uint": "0x64" } },
+				]
+			})
+		);
+		assert_eq!(serde_json::from_value::<Log>(json).unwrap(), log);
+	}
+}
@@ -0,0 +1,92 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Encoding of batches of calls.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{encode, signature::short_signature, Address, Bytes, Function, ParamType, Result, Token};
+
+/// Encodes the call data of each call, in order.
+///
+/// Fails if the tokens of any call don't match the inputs of its function.
+pub fn encode_multicall(calls: &[(&Function, &[Token])]) -> Result<Vec<Bytes>> {
+	calls.iter().map(|(function, tokens)| function.encode_input(tokens)).collect()
+}
+
+/// Encodes a call of `aggregate((address,bytes)[])` of the Multicall3 contract,
+/// which calls each target with the call data of its function and tokens.
+///
+/// Fails if the tokens of any call don't match the inputs of its function.
+pub fn encode_multicall3(calls: &[(Address, &Function, &[Token])]) -> Result<Bytes> {
+	let calls = calls
+		.iter()
+		.map(|(target, function, tokens)| {
+			Ok(Token::Tuple(vec![Token::Address(*target), Token::Bytes(function.encode_input(tokens)?)]))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let call = ParamType::Tuple(vec![ParamType::Address, ParamType::Bytes]);
+	let selector = short_signature("aggregate", &[ParamType::Array(Box::new(call))]);
+	Ok(selector.iter().copied().chain(encode(&[Token::Array(calls)])).collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{encode_multicall, encode_multicall3, Address, Function, Param, ParamType, StateMutability, Token};
+
+	fn balance_of() -> Function {
+		#[allow(deprecated)]
+		Function {
+			name: "balanceOf".to_owned(),
+			inputs: vec![Param::new("owner", ParamType::Address)],
+			outputs: vec![Param::new("", ParamType::Uint(256))],
+			constant: None,
+			state_mutability: StateMutability::View,
+		}
+	}
+
+	#[test]
+	fn multicall() {
+		let function = balance_of();
+		let alice = [Token::Address(Address::repeat_byte(0x11))];
+		let bob = [Token::Address(Address::repeat_byte(0x22))];
+
+		let encoded = encode_multicall(&[(&function, &alice), (&function, &bob)]).unwrap();
+		assert_eq!(encoded, vec![function.encode_input(&alice).unwrap(), function.encode_input(&bob).unwrap()]);
+		assert!(encode_multicall(&[(&function, &[Token::Bool(true)])]).is_err());
+	}
+
+	#[test]
+	fn multicall3() {
+		let function = balance_of();
+		let token = Address::repeat_byte(0xaa);
+		let alice = [Token::Address(Address::repeat_byte(0x11))];
+
+		let encoded = encode_multicall3(&[(token, &function, &alice)]).unwrap();
+		let expected = hex!(
+			"
+			252dba42
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000020
+			000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+			0000000000000000000000000000000000000000000000000000000000000040
+			0000000000000000000000000000000000000000000000000000000000000024
+			70a0823100000000000000000000000011111111111111111111111111111111
+			1111111100000000000000000000000000000000000000000000000000000000
+		"
+		);
+		assert_eq!(encoded, expected);
+		assert!(encode_multicall3(&[(token, &function, &[])]).is_err());
+	}
+}
@@ -8,7 +8,7 @@
 
 //! Operation type.
 
-use crate::{error::Error, Constructor, Event, Function};
+use crate::{error::Error, Constructor, Event, Function, StateMutability};
 use serde::{Deserialize, Serialize};
 
 /// Operation type.
@@ -24,23 +24,98 @@ pub enum Operation {
 	/// Contract event.
 	#[serde(rename = "event")]
 	Event(Event),
-	/// Contract event.
+	/// Contract error.
 	#[serde(rename = "error")]
 	Error(Error),
 	/// Fallback function.
 	#[serde(rename = "fallback")]
-	Fallback,
+	Fallback {
+		/// State mutability of the fallback function, either `payable` or `nonpayable`.
+		#[serde(rename = "stateMutability", default)]
+		state_mutability: StateMutability,
+	},
 	/// Receive function.
 	#[serde(rename = "receive")]
 	Receive,
 }
 
+/// Borrowing counterpart of `Operation`, used to serialize ABI entries
+/// without cloning them.
+#[derive(Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum OperationRef<'a> {
+	#[serde(rename = "constructor")]
+	Constructor(&'a Constructor),
+
+	#[serde(rename = "function")]
+	Function(&'a Function),
+
+	#[serde(rename = "event")]
+	Event(&'a Event),
+
+	#[serde(rename = "error")]
+	Error(&'a Error),
+
+	#[serde(rename = "fallback")]
+	Fallback {
+		#[serde(rename = "stateMutability")]
+		state_mutability: StateMutability,
+	},
+
+	#[serde(rename = "receive")]
+	Receive,
+}
+
+#[cfg(feature = "full-serde")]
+impl OperationRef<'_> {
+	/// Serializes the ABI entry to its JSON fragment.
+	pub(crate) fn to_json_value(&self) -> serde_json::Value {
+		serde_json::to_value(self).expect("ABI entries always serialize to JSON")
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::Operation;
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{tests::assert_ser_de, Event, EventParam, Function, Param, ParamType, StateMutability};
+	use crate::{
+		tests::{assert_ser_de, component},
+		AbiError, Event, EventParam, Function, Param, ParamType, StateMutability, TupleParam,
+	};
+
+	#[test]
+	fn fallback_operation() {
+		let deserialized: Operation = serde_json::from_str(r#"{ "type": "fallback" }"#).unwrap();
+		assert_eq!(deserialized, Operation::Fallback { state_mutability: StateMutability::NonPayable });
+
+		let s = r#"{ "type": "fallback", "stateMutability": "payable" }"#;
+		let deserialized: Operation = serde_json::from_str(s).unwrap();
+		assert_eq!(deserialized, Operation::Fallback { state_mutability: StateMutability::Payable });
+
+		assert_ser_de(&deserialized);
+	}
+
+	#[test]
+	fn legacy_function_operation() {
+		let state_mutability = |attributes: &str| {
+			let s = format!(r#"{{ "type": "function", "name": "foo", "inputs": [], "outputs": [] {attributes} }}"#);
+			match serde_json::from_str(&s).unwrap() {
+				Operation::Function(function) => function.state_mutability,
+				operation => panic!("unexpected operation: {operation:?}"),
+			}
+		};
+
+		assert_eq!(state_mutability(""), StateMutability::NonPayable);
+		assert_eq!(state_mutability(r#", "constant": false, "payable": false"#), StateMutability::NonPayable);
+		assert_eq!(state_mutability(r#", "constant": false, "payable": true"#), StateMutability::Payable);
+		assert_eq!(state_mutability(r#", "constant": true, "payable": false"#), StateMutability::View);
+		assert_eq!(
+			state_mutability(r#", "payable": true, "stateMutability": "nonpayable""#),
+			StateMutability::NonPayable
+		);
+		assert_eq!(state_mutability(r#", "constant": true, "stateMutability": "pure""#), StateMutability::Pure);
+	}
 
 	#[test]
 	fn operation() {
@@ -59,7 +134,12 @@ mod tests {
 		#[allow(deprecated)]
 		let function = Function {
 			name: "foo".to_owned(),
-			inputs: vec![Param { name: "a".to_owned(), kind: ParamType::Address, internal_type: None }],
+			inputs: vec![Param {
+				name: "a".to_owned(),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![],
+			}],
 			outputs: vec![],
 			constant: None,
 			state_mutability: StateMutability::NonPayable,
@@ -69,6 +149,35 @@ mod tests {
 		assert_ser_de(&deserialized);
 	}
 
+	#[test]
+	fn error_operation() {
+		let s = r#"{
+			"type":"error",
+			"inputs": [{
+				"name":"available",
+				"type":"uint256"
+			}],
+			"name":"InsufficientBalance"
+		}"#;
+
+		let deserialized: Operation = serde_json::from_str(s).unwrap();
+
+		assert_eq!(
+			deserialized,
+			Operation::Error(AbiError {
+				name: "InsufficientBalance".to_owned(),
+				inputs: vec![Param {
+					name: "available".to_owned(),
+					kind: ParamType::Uint(256),
+					internal_type: None,
+					components: vec![]
+				}],
+			})
+		);
+
+		assert_ser_de(&deserialized);
+	}
+
 	#[test]
 	fn event_operation_with_tuple_array_input() {
 		let s = r#"{
@@ -115,7 +224,7 @@ mod tests {
 			Operation::Event(Event {
 				name: "E".to_owned(),
 				inputs: vec![
-					EventParam { name: "a".to_owned(), kind: ParamType::Address, indexed: true },
+					EventParam { name: "a".to_owned(), kind: ParamType::Address, indexed: true, components: vec![] },
 					EventParam {
 						name: "b".to_owned(),
 						kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
@@ -123,7 +232,21 @@ mod tests {
 							ParamType::Uint(256),
 							ParamType::Bytes
 						]))),
-						indexed: false
+						indexed: false,
+						components: vec![
+							TupleParam {
+								internal_type: Some("address".to_owned()),
+								..component(Some("to"), ParamType::Address, vec![])
+							},
+							TupleParam {
+								internal_type: Some("uint256".to_owned()),
+								..component(Some("value"), ParamType::Uint(256), vec![])
+							},
+							TupleParam {
+								internal_type: Some("bytes".to_owned()),
+								..component(Some("data"), ParamType::Bytes, vec![])
+							}
+						]
 					},
 				],
 				anonymous: false,
@@ -200,4 +323,33 @@ mod tests {
 		test_sanitize_event_name("()", "");
 		test_sanitize_event_name("", "");
 	}
+
+	#[test]
+	#[cfg(feature = "full-serde")]
+	fn to_json_value() {
+		let json = r#"[
+			{ "type": "constructor", "inputs": [{ "name": "a", "type": "uint256" }] },
+			{
+				"type": "function",
+				"name": "foo",
+				"inputs": [{ "name": "a", "type": "address" }],
+				"outputs": [],
+				"stateMutability": "view"
+			},
+			{ "type": "event", "name": "Foo", "inputs": [{ "name": "a", "type": "address", "indexed": true }], "anonymous": false },
+			{ "type": "error", "name": "Bar", "inputs": [] }
+		]"#;
+		let contract = crate::Contract::load(json.as_bytes()).unwrap();
+
+		let fragments = [
+			contract.constructor().unwrap().to_json_value(),
+			contract.function("foo").unwrap().to_json_value(),
+			contract.event("Foo").unwrap().to_json_value(),
+			contract.error("Bar").unwrap().to_json_value(),
+		];
+
+		assert_eq!(fragments[1]["type"], "function");
+		assert_eq!(fragments[1]["name"], "foo");
+		assert_eq!(serde_json::Value::Array(fragments.to_vec()), serde_json::to_value(&contract).unwrap());
+	}
 }
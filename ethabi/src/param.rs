@@ -20,9 +20,9 @@ use serde::{
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
 #[cfg(feature = "serde")]
-use crate::{param_type::Writer, TupleParam};
+use crate::param_type::Writer;
+use crate::{ParamType, TupleParam};
 
 /// Function param.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,69 @@ pub struct Param {
 	pub kind: ParamType,
 	/// Additional Internal type.
 	pub internal_type: Option<String>,
+	/// Names and internal types of the components of a tuple, or of a tuple
+	/// array element. Empty if they are unknown or if the param is not a tuple.
+	pub components: Vec<TupleParam>,
+}
+
+/// Classification of the `internalType` of a param.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InternalTypeKind {
+	/// Elementary type such as `uint256` or `address payable`, also used when
+	/// the internal type is unknown.
+	Elementary,
+	/// Enum, e.g. `MyContract.Status` for `enum MyContract.Status`.
+	Enum {
+		/// Qualified enum name.
+		name: String,
+	},
+	/// Contract or interface, e.g. `IERC20` for `contract IERC20`.
+	Contract {
+		/// Contract name.
+		name: String,
+	},
+	/// Struct, e.g. `Foo.Bar` for `struct Foo.Bar` or `struct Foo.Bar[]`.
+	Struct {
+		/// Qualified struct name.
+		name: String,
+	},
+}
+
+impl Param {
+	/// Classifies the internal type of this param. Array suffixes are not part
+	/// of the returned name.
+	pub fn internal_type_kind(&self) -> InternalTypeKind {
+		let internal_type = match self.internal_type {
+			Some(ref internal_type) => internal_type.as_str(),
+			None => return InternalTypeKind::Elementary,
+		};
+		let (prefix, name) = match internal_type.split_once(' ') {
+			Some(split) => split,
+			None => return InternalTypeKind::Elementary,
+		};
+		let name = match name.find('[') {
+			Some(pos) => &name[..pos],
+			None => name,
+		}
+		.to_owned();
+
+		match prefix {
+			"enum" => InternalTypeKind::Enum { name },
+			"contract" => InternalTypeKind::Contract { name },
+			"struct" => InternalTypeKind::Struct { name },
+			_ => InternalTypeKind::Elementary,
+		}
+	}
+
+	/// Creates a param without an internal type.
+	pub fn new(name: impl Into<String>, kind: ParamType) -> Self {
+		Param { name: name.into(), kind, internal_type: None, components: vec![] }
+	}
+
+	/// Creates a param with the given internal type, such as `struct Foo.Bar` or `contract IERC20`.
+	pub fn with_internal_type(name: impl Into<String>, kind: ParamType, internal_type: impl Into<String>) -> Self {
+		Param { name: name.into(), kind, internal_type: Some(internal_type.into()), components: vec![] }
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -97,8 +160,8 @@ impl<'a> Visitor<'a> for ParamVisitor {
 		}
 		let name = name.ok_or_else(|| Error::missing_field("name"))?;
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		set_tuple_components::<V::Error>(&mut kind, components)?;
-		Ok(Param { name, kind, internal_type })
+		let components = set_tuple_components::<V::Error>(&mut kind, components)?;
+		Ok(Param { name, kind, internal_type, components })
 	}
 }
 
@@ -116,7 +179,7 @@ impl Serialize for Param {
 		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
 		if let Some(inner_tuple) = inner_tuple(&self.kind) {
 			map.serialize_key("components")?;
-			map.serialize_value(&SerializeableParamVec(inner_tuple))?;
+			map.serialize_value(&SerializeableParamVec(inner_tuple, &self.components))?;
 		}
 		map.end()
 	}
@@ -146,20 +209,27 @@ pub(crate) fn inner_tuple(mut param: &ParamType) -> Option<&Vec<ParamType>> {
 	}
 }
 
+/// Sets the types of the inner tuple of `kind` and returns the components,
+/// which keep their names and internal types.
 #[cfg(feature = "serde")]
 pub(crate) fn set_tuple_components<Error: serde::de::Error>(
 	kind: &mut ParamType,
 	components: Option<Vec<TupleParam>>,
-) -> Result<(), Error> {
-	if let Some(inner_tuple_mut) = inner_tuple_mut(kind) {
-		let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
-		inner_tuple_mut.extend(tuple_params.into_iter().map(|param| param.kind))
+) -> Result<Vec<TupleParam>, Error> {
+	match inner_tuple_mut(kind) {
+		Some(inner_tuple_mut) => {
+			let tuple_params = components.ok_or_else(|| Error::missing_field("components"))?;
+			inner_tuple_mut.extend(tuple_params.iter().map(|param| param.kind.clone()));
+			Ok(tuple_params)
+		}
+		None => Ok(vec![]),
 	}
-	Ok(())
 }
 
+/// Serializes the components of a tuple. Types are taken from the tuple
+/// itself, names and internal types from the components when they match it.
 #[cfg(feature = "serde")]
-pub(crate) struct SerializeableParamVec<'a>(pub(crate) &'a [ParamType]);
+pub(crate) struct SerializeableParamVec<'a>(pub(crate) &'a [ParamType], pub(crate) &'a [TupleParam]);
 
 #[cfg(feature = "serde")]
 impl Serialize for SerializeableParamVec<'_> {
@@ -167,16 +237,17 @@ impl Serialize for SerializeableParamVec<'_> {
 	where
 		S: Serializer,
 	{
+		let components = if self.1.len() == self.0.len() { self.1 } else { &[] };
 		let mut seq = serializer.serialize_seq(None)?;
-		for param in self.0 {
-			seq.serialize_element(&SerializeableParam(param))?;
+		for (i, param) in self.0.iter().enumerate() {
+			seq.serialize_element(&SerializeableParam(param, components.get(i)))?;
 		}
 		seq.end()
 	}
 }
 
 #[cfg(feature = "serde")]
-pub(crate) struct SerializeableParam<'a>(pub(crate) &'a ParamType);
+pub(crate) struct SerializeableParam<'a>(pub(crate) &'a ParamType, pub(crate) Option<&'a TupleParam>);
 
 #[cfg(feature = "serde")]
 impl Serialize for SerializeableParam<'_> {
@@ -185,10 +256,17 @@ impl Serialize for SerializeableParam<'_> {
 		S: Serializer,
 	{
 		let mut map = serializer.serialize_map(None)?;
+		if let Some(internal_type) = self.1.and_then(|param| param.internal_type.as_ref()) {
+			map.serialize_entry("internalType", internal_type)?;
+		}
+		if let Some(name) = self.1.and_then(|param| param.name.as_ref()) {
+			map.serialize_entry("name", name)?;
+		}
 		map.serialize_entry("type", &Writer::write_for_abi(self.0, false))?;
 		if let Some(inner_tuple) = inner_tuple(self.0) {
+			let components = self.1.map(|param| param.components.as_slice()).unwrap_or_default();
 			map.serialize_key("components")?;
-			map.serialize_value(&SerializeableParamVec(inner_tuple))?;
+			map.serialize_value(&SerializeableParamVec(inner_tuple, components))?;
 		}
 		map.end()
 	}
@@ -199,10 +277,35 @@ mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
 	use crate::{
-		tests::{assert_json_eq, assert_ser_de},
-		Param, ParamType,
+		tests::{assert_json_eq, assert_ser_de, component},
+		InternalTypeKind, Param, ParamType,
 	};
 
+	#[test]
+	fn param_internal_type_kind() {
+		let kind = |internal_type: Option<&str>| {
+			Param {
+				name: "foo".to_owned(),
+				kind: ParamType::Address,
+				internal_type: internal_type.map(ToOwned::to_owned),
+				components: vec![],
+			}
+			.internal_type_kind()
+		};
+
+		assert_eq!(kind(None), InternalTypeKind::Elementary);
+		assert_eq!(kind(Some("uint8")), InternalTypeKind::Elementary);
+		assert_eq!(kind(Some("address payable")), InternalTypeKind::Elementary);
+		assert_eq!(kind(Some("enum MyContract.Status")), InternalTypeKind::Enum { name: "MyContract.Status".into() });
+		assert_eq!(kind(Some("contract IERC20")), InternalTypeKind::Contract { name: "IERC20".into() });
+		assert_eq!(kind(Some("contract IERC20[2]")), InternalTypeKind::Contract { name: "IERC20".into() });
+		assert_eq!(kind(Some("struct Verifier.Proof")), InternalTypeKind::Struct { name: "Verifier.Proof".into() });
+		assert_eq!(
+			kind(Some("struct Verifier.Proof[][3]")),
+			InternalTypeKind::Struct { name: "Verifier.Proof".into() }
+		);
+	}
+
 	#[test]
 	fn param_simple() {
 		let s = r#"{
@@ -212,7 +315,11 @@ mod tests {
 
 		let deserialized: Param = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None });
+		assert_eq!(
+			deserialized,
+			Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None, components: vec![] }
+		);
+		assert_eq!(deserialized, Param::new("foo", ParamType::Address));
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -232,9 +339,11 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Address,
-				internal_type: Some("struct Verifier.Proof".to_string())
+				internal_type: Some("struct Verifier.Proof".to_string()),
+				components: vec![]
 			}
 		);
+		assert_eq!(deserialized, Param::with_internal_type("foo", ParamType::Address, "struct Verifier.Proof"));
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -266,7 +375,15 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(
+						None,
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -301,7 +418,15 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: Some("struct Pairing.G1Point[]".to_string())
+				internal_type: Some("struct Pairing.G1Point[]".to_string()),
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(
+						None,
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -338,7 +463,15 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(Some("amount"), ParamType::Uint(48), vec![]),
+					component(
+						Some("things"),
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(Some("baseTupleParam"), ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -374,7 +507,12 @@ mod tests {
 					ParamType::Address,
 					ParamType::Address
 				]))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(None, ParamType::Address, vec![]),
+					component(None, ParamType::Address, vec![])
+				]
 			}
 		);
 
@@ -405,7 +543,11 @@ mod tests {
 					ParamType::Uint(8),
 					ParamType::Uint(16),
 				]))))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(8), vec![]),
+					component(None, ParamType::Uint(16), vec![])
+				]
 			}
 		);
 
@@ -440,7 +582,12 @@ mod tests {
 					Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
 					2
 				),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(None, ParamType::Address, vec![]),
+					component(None, ParamType::Address, vec![])
+				]
 			}
 		);
 
@@ -482,7 +629,19 @@ mod tests {
 					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
 					ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
 				]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(
+						None,
+						ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+						vec![component(None, ParamType::Address, vec![])]
+					),
+					component(
+						None,
+						ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -12,12 +12,12 @@
 mod deserialize;
 
 mod param_type;
+pub(crate) use param_type::heads_size;
 pub use param_type::ParamType;
 
-#[cfg(feature = "serde")]
 mod reader;
-#[cfg(feature = "serde")]
 pub use reader::Reader;
+pub(crate) use reader::{closing_paren, split_top_level};
 
 mod writer;
-pub use writer::Writer;
+pub use writer::{render_solidity_struct, Writer};
@@ -15,7 +15,12 @@ use super::Writer;
 use crate::no_std_prelude::*;
 
 /// Function and event param types.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Types are totally ordered, first by variant in the order in which they are
+/// declared here, then by their contents: sizes numerically, array element
+/// types before fixed array lengths, and tuples lexicographically by their
+/// component types. This ordering is stable across versions.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ParamType {
 	/// Address.
 	Address,
@@ -45,6 +50,16 @@ impl fmt::Display for ParamType {
 	}
 }
 
+#[cfg(feature = "serde")]
+impl core::str::FromStr for ParamType {
+	type Err = crate::Error;
+
+	/// Parses a param type with [`Reader::read`](crate::param_type::Reader::read).
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		super::Reader::read(s)
+	}
+}
+
 impl ParamType {
 	/// returns whether a zero length byte slice (`0x`) is
 	/// a valid encoded form of this param type
@@ -66,6 +81,161 @@ impl ParamType {
 			_ => false,
 		}
 	}
+
+	/// Returns the component types of a tuple, or `None` if this isn't a tuple.
+	pub fn components(&self) -> Option<&[ParamType]> {
+		match self {
+			ParamType::Tuple(params) => Some(params),
+			_ => None,
+		}
+	}
+
+	/// Returns the element type of an array or fixed array, or `None` if this
+	/// isn't an array.
+	pub fn element(&self) -> Option<&ParamType> {
+		match self {
+			ParamType::Array(elem_type) | ParamType::FixedArray(elem_type, _) => Some(elem_type),
+			_ => None,
+		}
+	}
+
+	/// Folds this type bottom-up: `f` is called with every type nested in this
+	/// one, including array elements and tuple components, along with the
+	/// results of folding its element or components, and finally with this type.
+	///
+	/// ```
+	/// use ethabi::ParamType;
+	///
+	/// // (address,uint256)[2][]
+	/// let tuple = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+	/// let ty = ParamType::Array(Box::new(ParamType::FixedArray(Box::new(tuple), 2)));
+	/// let depth = ty.fold(&mut |_, inner: Vec<usize>| 1 + inner.into_iter().max().unwrap_or(0));
+	/// assert_eq!(depth, 4);
+	/// ```
+	pub fn fold<T>(&self, f: &mut impl FnMut(&ParamType, Vec<T>) -> T) -> T {
+		let inner = match self {
+			ParamType::Array(inner) | ParamType::FixedArray(inner, _) => vec![inner.fold(f)],
+			ParamType::Tuple(inner) => inner.iter().map(|param| param.fold(f)).collect(),
+			_ => Vec::new(),
+		};
+		f(self, inner)
+	}
+
+	/// Returns a copy of this type with every nested type, and finally the type
+	/// itself, replaced by the result of `f`. Nested types are mapped first, so
+	/// `f` sees array elements and tuple components that are already mapped.
+	///
+	/// ```
+	/// use ethabi::ParamType;
+	///
+	/// let ty = ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Uint(8), ParamType::Bytes])));
+	/// let widened = ty.map(&mut |ty| match ty {
+	///     ParamType::Uint(_) => ParamType::Uint(256),
+	///     ty => ty,
+	/// });
+	/// assert_eq!(widened.to_string(), "(uint256,bytes)[]");
+	/// ```
+	pub fn map(&self, f: &mut impl FnMut(ParamType) -> ParamType) -> ParamType {
+		let ty = match self {
+			ParamType::Array(inner) => ParamType::Array(Box::new(inner.map(f))),
+			ParamType::FixedArray(inner, len) => ParamType::FixedArray(Box::new(inner.map(f)), *len),
+			ParamType::Tuple(inner) => ParamType::Tuple(inner.iter().map(|param| param.map(f)).collect()),
+			ty => ty.clone(),
+		};
+		f(ty)
+	}
+
+	/// Returns a structured description of this type, e.g. for generating input
+	/// forms. Every type is an object with a `kind` and, depending on the kind,
+	/// `bits` for integers, `size` for fixed bytes, `element` and `length` for
+	/// arrays and `components` for tuples.
+	///
+	/// ```
+	/// use ethabi::ParamType;
+	/// use serde_json::json;
+	///
+	/// let ty: ParamType = "(uint256,bytes4)[]".parse().unwrap();
+	/// assert_eq!(
+	///     ty.describe(),
+	///     json!({
+	///         "kind": "array",
+	///         "element": {
+	///             "kind": "tuple",
+	///             "components": [{ "kind": "uint", "bits": 256 }, { "kind": "fixed_bytes", "size": 4 }],
+	///         },
+	///     })
+	/// );
+	/// ```
+	#[cfg(feature = "full-serde")]
+	pub fn describe(&self) -> serde_json::Value {
+		use serde_json::json;
+
+		self.fold(&mut |ty, mut inner| match ty {
+			ParamType::Address => json!({ "kind": "address" }),
+			ParamType::Bytes => json!({ "kind": "bytes" }),
+			ParamType::Int(bits) => json!({ "kind": "int", "bits": bits }),
+			ParamType::Uint(bits) => json!({ "kind": "uint", "bits": bits }),
+			ParamType::Bool => json!({ "kind": "bool" }),
+			ParamType::String => json!({ "kind": "string" }),
+			ParamType::Array(_) => json!({ "kind": "array", "element": inner.remove(0) }),
+			ParamType::FixedBytes(size) => json!({ "kind": "fixed_bytes", "size": size }),
+			ParamType::FixedArray(_, len) => {
+				json!({ "kind": "fixed_array", "element": inner.remove(0), "length": len })
+			}
+			ParamType::Tuple(_) => json!({ "kind": "tuple", "components": inner }),
+		})
+	}
+
+	/// Number of bytes taken up by the head of a value of this type, which is
+	/// the whole encoding of static types and the offset word of dynamic ones.
+	/// Returns `None` if the size overflows `usize`.
+	pub(crate) fn head_size(&self) -> Option<usize> {
+		match self {
+			ParamType::FixedArray(inner, len) if !self.is_dynamic() => inner.head_size()?.checked_mul(*len),
+			ParamType::Tuple(inner) if !self.is_dynamic() => heads_size(inner),
+			_ => Some(32),
+		}
+	}
+
+	/// Returns the largest number of bytes the ABI encoding of a value of this
+	/// type can take, given upper bounds for its dynamically sized parts.
+	///
+	/// `bounds` is called with every `bytes`, `string` and dynamic array type
+	/// found in this type and returns the maximum length in bytes, respectively
+	/// elements, of values of that type. Values with exactly these lengths encode
+	/// to exactly this many bytes. Returns `None` if the size overflows `usize`.
+	///
+	/// ```
+	/// use ethabi::ParamType;
+	///
+	/// let ty = ParamType::Array(Box::new(ParamType::Address));
+	/// // offset, length and 100 addresses
+	/// assert_eq!(ty.max_encoded_size(&|_| 100), Some(32 + 32 + 100 * 32));
+	/// assert_eq!(ty.max_encoded_size(&|_| usize::MAX), None);
+	/// ```
+	pub fn max_encoded_size(&self, bounds: &dyn Fn(&ParamType) -> usize) -> Option<usize> {
+		self.head_size()?.checked_add(self.max_tail_size(bounds)?)
+	}
+
+	fn max_tail_size(&self, bounds: &dyn Fn(&ParamType) -> usize) -> Option<usize> {
+		match self {
+			_ if !self.is_dynamic() => Some(0),
+			// + 32 for the length prepended to the contents
+			ParamType::Bytes | ParamType::String => bounds(self).div_ceil(32).checked_mul(32)?.checked_add(32),
+			ParamType::Array(inner) => bounds(self).checked_mul(inner.max_encoded_size(bounds)?)?.checked_add(32),
+			ParamType::FixedArray(inner, len) => len.checked_mul(inner.max_encoded_size(bounds)?),
+			ParamType::Tuple(inner) => {
+				inner.iter().try_fold(0usize, |size, param| size.checked_add(param.max_encoded_size(bounds)?))
+			}
+			_ => Some(0),
+		}
+	}
+}
+
+/// Number of bytes taken up by the heads of values of `types`, or `None` if
+/// the size overflows `usize`.
+pub(crate) fn heads_size(types: &[ParamType]) -> Option<usize> {
+	types.iter().try_fold(0usize, |size, kind| size.checked_add(kind.head_size()?))
 }
 
 #[cfg(test)]
@@ -74,6 +244,154 @@ mod tests {
 	use crate::no_std_prelude::*;
 	use crate::ParamType;
 
+	#[test]
+	fn test_max_encoded_size() {
+		use crate::{encoded_size, Token};
+
+		let bounds = |ty: &ParamType| match ty {
+			ParamType::Bytes => 33,
+			ParamType::String => 3,
+			ParamType::Array(inner) if **inner == ParamType::Uint(256) => 4,
+			_ => 2,
+		};
+		let ty = ParamType::Tuple(vec![
+			ParamType::Uint(256),
+			ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bytes, ParamType::Bool]))),
+			ParamType::FixedArray(Box::new(ParamType::String), 2),
+			ParamType::Array(Box::new(ParamType::Uint(256))),
+		]);
+		let bytes = Token::Tuple(vec![Token::Bytes(vec![0; 33]), Token::Bool(true)]);
+		let token = Token::Tuple(vec![
+			Token::Uint(1.into()),
+			Token::Array(vec![bytes.clone(), bytes]),
+			Token::FixedArray(vec![Token::String("abc".into()), Token::String("def".into())]),
+			Token::Array(vec![Token::Uint(2.into()); 4]),
+		]);
+
+		assert_eq!(ty.max_encoded_size(&bounds), Some(encoded_size(&[token])));
+		assert_eq!(ParamType::FixedArray(Box::new(ParamType::Address), 3).max_encoded_size(&|_| 100), Some(96));
+		assert_eq!(ParamType::String.max_encoded_size(&|_| 0), Some(64));
+
+		// sizes which don't fit in a usize
+		assert_eq!(ParamType::Bytes.max_encoded_size(&|_| usize::MAX), None);
+		let ty = ParamType::FixedArray(Box::new(ParamType::Array(Box::new(ParamType::Address))), usize::MAX / 64);
+		assert_eq!(ty.max_encoded_size(&|_| 1), None);
+	}
+
+	#[test]
+	fn test_components_and_element() {
+		let tuple = ParamType::Tuple(vec![ParamType::Bool, ParamType::Address]);
+		assert_eq!(tuple.components(), Some(&[ParamType::Bool, ParamType::Address][..]));
+		assert_eq!(tuple.element(), None);
+
+		let array = ParamType::Array(Box::new(tuple.clone()));
+		assert_eq!(array.element(), Some(&tuple));
+		assert_eq!(array.components(), None);
+		assert_eq!(ParamType::FixedArray(Box::new(ParamType::Bytes), 2).element(), Some(&ParamType::Bytes));
+
+		assert_eq!(ParamType::Uint(256).components(), None);
+		assert_eq!(ParamType::Uint(256).element(), None);
+	}
+
+	#[test]
+	fn test_fold_and_map() {
+		let ty = ParamType::FixedArray(
+			Box::new(ParamType::Tuple(vec![
+				ParamType::Uint(8),
+				ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Int(16), ParamType::Bool]))),
+			])),
+			2,
+		);
+
+		let tuples = ty.fold(&mut |ty, inner: Vec<usize>| {
+			inner.into_iter().sum::<usize>() + matches!(ty, ParamType::Tuple(_)) as usize
+		});
+		assert_eq!(tuples, 2);
+
+		let mut visited = Vec::new();
+		ty.fold(&mut |ty, _: Vec<()>| visited.push(ty.to_string()));
+		assert_eq!(
+			visited,
+			[
+				"uint8",
+				"int16",
+				"bool",
+				"(int16,bool)",
+				"(int16,bool)[]",
+				"(uint8,(int16,bool)[])",
+				"(uint8,(int16,bool)[])[2]"
+			]
+		);
+
+		let widened = ty.map(&mut |ty| match ty {
+			ParamType::Uint(_) => ParamType::Uint(256),
+			ParamType::Int(_) => ParamType::Int(256),
+			ty => ty,
+		});
+		assert_eq!(widened.to_string(), "(uint256,(int256,bool)[])[2]");
+		assert_eq!(ty.map(&mut |ty| ty), ty);
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_from_str() {
+		assert_eq!("uint256[]".parse::<ParamType>().unwrap(), ParamType::Array(Box::new(ParamType::Uint(256))));
+		assert_eq!(
+			"(address,bytes32)[2]".parse::<ParamType>().unwrap(),
+			ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::FixedBytes(32)])), 2)
+		);
+		assert!("uint7".parse::<ParamType>().is_err());
+	}
+
+	#[test]
+	fn test_param_type_ordering() {
+		let mut types = vec![
+			ParamType::Tuple(vec![ParamType::Uint(8), ParamType::Bool]),
+			ParamType::FixedArray(Box::new(ParamType::Address), 3),
+			ParamType::Tuple(vec![ParamType::Uint(8)]),
+			ParamType::FixedBytes(32),
+			ParamType::Array(Box::new(ParamType::Bool)),
+			ParamType::String,
+			ParamType::Bool,
+			ParamType::Uint(256),
+			ParamType::FixedArray(Box::new(ParamType::Address), 2),
+			ParamType::Uint(8),
+			ParamType::Int(256),
+			ParamType::Array(Box::new(ParamType::Address)),
+			ParamType::Bytes,
+			ParamType::Address,
+			ParamType::FixedBytes(1),
+			ParamType::FixedArray(Box::new(ParamType::Bytes), 1),
+		];
+		types.sort();
+
+		let sorted = types.iter().map(ToString::to_string).collect::<Vec<_>>();
+		assert_eq!(
+			sorted,
+			vec![
+				"address",
+				"bytes",
+				"int256",
+				"uint8",
+				"uint256",
+				"bool",
+				"string",
+				"address[]",
+				"bool[]",
+				"bytes1",
+				"bytes32",
+				"address[2]",
+				"address[3]",
+				"bytes[1]",
+				"(uint8)",
+				"(uint8,bool)",
+			]
+		);
+
+		let set = types.iter().cloned().chain(types.iter().cloned()).collect::<alloc::collections::BTreeSet<_>>();
+		assert_eq!(set.into_iter().collect::<Vec<_>>(), types);
+	}
+
 	#[test]
 	fn test_param_type_display() {
 		assert_eq!(format!("{}", ParamType::Address), "address".to_owned());
@@ -106,4 +424,38 @@ mod tests {
 		assert!(ParamType::FixedArray(Box::new(ParamType::String), 2).is_dynamic());
 		assert!(ParamType::FixedArray(Box::new(ParamType::Array(Box::new(ParamType::Bool))), 2).is_dynamic());
 	}
+
+	#[cfg(feature = "full-serde")]
+	#[test]
+	fn test_describe() {
+		use serde_json::json;
+
+		let ty = ParamType::FixedArray(
+			Box::new(ParamType::Tuple(vec![
+				ParamType::Address,
+				ParamType::Int(8),
+				ParamType::Bool,
+				ParamType::String,
+				ParamType::Bytes,
+			])),
+			2,
+		);
+		assert_eq!(
+			ty.describe(),
+			json!({
+				"kind": "fixed_array",
+				"length": 2,
+				"element": {
+					"kind": "tuple",
+					"components": [
+						{ "kind": "address" },
+						{ "kind": "int", "bits": 8 },
+						{ "kind": "bool" },
+						{ "kind": "string" },
+						{ "kind": "bytes" },
+					],
+				},
+			})
+		);
+	}
 }
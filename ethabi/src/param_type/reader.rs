@@ -23,116 +23,63 @@ impl Reader {
 					return Err(Error::InvalidName(name.to_owned()));
 				};
 
+				let inner = &name[1..name.len() - 1];
+				let read_component = |component: &str| match component {
+					"" => Err(Error::InvalidName(name.to_owned())),
+					_ => Reader::read(component),
+				};
 				let mut subtypes = Vec::new();
-				let mut subtuples = Vec::new();
-				let mut nested = 0isize;
-				let mut top_level_paren_open = 0usize;
-				let mut last_item = 1;
-				let mut chars = name.chars().enumerate();
-
-				// Iterate over name and build the nested tuple structure
-				while let Some((mut pos, c)) = chars.next() {
+				// Nesting depth of parentheses and brackets, so that commas inside
+				// nested tuples and their array suffixes are not treated as
+				// separators of the top level components.
+				let mut depth = 0usize;
+				let mut last_item = 0;
+				for (pos, c) in inner.char_indices() {
 					match c {
-						'(' => {
-							top_level_paren_open = pos;
-							nested += 1;
-							// If an '(' is encountered within the tuple
-							// insert an empty subtuples vector to be filled
-							if nested > 1 {
-								subtuples.push(vec![]);
-								last_item = pos + 1;
-							}
-						}
-						')' => {
-							nested -= 1;
-
-							// End parsing and return an error if parentheses aren't symmetrical
-							if nested < 0 {
-								return Err(Error::InvalidName(name.to_owned()));
-							}
-							// If there have not been any characters since the last item
-							// increment position without inserting any subtypes
-							else if name[last_item..pos].is_empty() {
-								last_item = pos + 1;
-							}
-							// If the item is in the top level of the tuple insert it into subtypes
-							else if nested == 0 {
-								// check for trailing brackets that indicate array of tuples
-								let sub = &name[last_item..pos];
-								let subtype = Reader::read(sub)?;
-								subtypes.push(subtype);
-								last_item = pos + 1;
-							}
-							// If the item is in a sublevel of the tuple
-							else if nested > 0 {
-								// this makes sure trailing brackets are included for the next step
-								loop {
-									match chars.clone().next() {
-										Some((_, ',')) | Some((_, ')')) | None => break,
-										_ => {
-											// consume the char and shift position
-											chars.next();
-											pos += 1;
-										}
-									}
-								}
-
-								// parse the nested tuple
-								let inner_tuple = &name[top_level_paren_open..=pos];
-								let subtype = Reader::read(inner_tuple)?;
-
-								if nested > 1 {
-									let mut subtuple = core::mem::take(&mut subtuples[(nested - 2) as usize]);
-									subtuple.push(subtype);
-									subtypes.push(ParamType::Tuple(subtuple));
-								} else {
-									subtypes.push(subtype);
-								}
-								last_item = pos + 1;
-							}
+						'(' | '[' => depth += 1,
+						')' | ']' => {
+							depth = depth.checked_sub(1).ok_or_else(|| Error::InvalidName(name.to_owned()))?;
 						}
-						',' => {
-							// If there have not been any characters since the last item
-							// increment position without inserting any subtypes
-							if name[last_item..pos].is_empty() {
-								last_item = pos + 1
-							}
-							// If the item is in the top level of the tuple insert it into subtypes
-							else if nested == 1 {
-								let sub = &name[last_item..pos];
-								let subtype = Reader::read(sub)?;
-								subtypes.push(subtype);
-								last_item = pos + 1;
-							}
-							// If the item is in a sublevel of the tuple
-							// insert it into the subtuple vector for the current depth level
-							else if nested > 1 {
-								let sub = &name[last_item..pos];
-								let subtype = Reader::read(sub)?;
-								subtuples[(nested - 2) as usize].push(subtype);
-								last_item = pos + 1;
-							}
+						',' if depth == 0 => {
+							subtypes.push(read_component(&inner[last_item..pos])?);
+							last_item = pos + 1;
 						}
 						_ => (),
 					}
 				}
+				if depth != 0 {
+					return Err(Error::InvalidName(name.to_owned()));
+				}
+				if !inner.is_empty() {
+					subtypes.push(read_component(&inner[last_item..])?);
+				}
 				return Ok(ParamType::Tuple(subtypes));
 			}
 			// check if it is a fixed or dynamic array.
 			Some(']') => {
+				let invalid = || Error::InvalidName(name.to_owned());
+				if !has_balanced_brackets(name) {
+					return Err(invalid());
+				}
+				let open = name.rfind('[').ok_or_else(invalid)?;
+				let subtype = &name[..open];
 				// take number part
-				let num: String =
-					name.chars().rev().skip(1).take_while(|c| *c != '[').collect::<String>().chars().rev().collect();
+				let num = &name[open + 1..name.len() - 1];
+				if subtype.is_empty() {
+					return Err(invalid());
+				}
 
-				let count = name.chars().count();
 				return if num.is_empty() {
 					// we already know it's a dynamic array!
-					let subtype = Reader::read(&name[..count - 2])?;
+					let subtype = Reader::read(subtype)?;
 					Ok(ParamType::Array(Box::new(subtype)))
 				} else {
 					// it's a fixed array.
+					if !num.bytes().all(|b| b.is_ascii_digit()) {
+						return Err(invalid());
+					}
 					let len = num.parse().map_err(Error::ParseInt)?;
-					let subtype = Reader::read(&name[..count - num.len() - 2])?;
+					let subtype = Reader::read(subtype)?;
 					Ok(ParamType::FixedArray(Box::new(subtype), len))
 				};
 			}
@@ -149,14 +96,25 @@ impl Reader {
 			"uint" => ParamType::Uint(256),
 			s if s.starts_with("int") => {
 				let len = s[3..].parse().map_err(Error::ParseInt)?;
+				if !is_valid_int_size(len) {
+					return Err(Error::InvalidName(name.to_owned()));
+				}
 				ParamType::Int(len)
 			}
 			s if s.starts_with("uint") => {
 				let len = s[4..].parse().map_err(Error::ParseInt)?;
+				if !is_valid_int_size(len) {
+					return Err(Error::InvalidName(name.to_owned()));
+				}
 				ParamType::Uint(len)
 			}
+			// `bytes0` is not a Solidity type. `ParamType::FixedBytes(0)` can still
+			// be constructed directly, but it is never produced by the parser.
 			s if s.starts_with("bytes") => {
 				let len = s[5..].parse().map_err(Error::ParseInt)?;
+				if !(1..=32).contains(&len) {
+					return Err(Error::InvalidName(name.to_owned()));
+				}
 				ParamType::FixedBytes(len)
 			}
 			// As discussed in https://github.com/rust-ethereum/ethabi/issues/254,
@@ -169,6 +127,108 @@ impl Reader {
 
 		Ok(result)
 	}
+
+	/// Parses a function signature into its name, input types and output types.
+	///
+	/// Outputs may follow the inputs after a colon or the `returns` keyword and
+	/// may be omitted:
+	/// - `transfer(address,uint256)`
+	/// - `balanceOf(address):(uint256)`
+	/// - `balanceOf(address) returns (uint256)`
+	pub fn read_function_signature(signature: &str) -> Result<(String, Vec<ParamType>, Vec<ParamType>), Error> {
+		let invalid = || Error::InvalidName(signature.to_owned());
+		let signature = signature.trim();
+
+		let params_start = signature.find('(').ok_or_else(invalid)?;
+		let name = &signature[..params_start];
+		if name.is_empty() || name.contains(char::is_whitespace) {
+			return Err(invalid());
+		}
+
+		let params_end = params_start + closing_paren(&signature[params_start..]).ok_or_else(invalid)? + 1;
+
+		let read_params = |params: &str| match params.ends_with(')') {
+			true => match Reader::read(params)? {
+				ParamType::Tuple(types) => Ok(types),
+				_ => Err(invalid()),
+			},
+			false => Err(invalid()),
+		};
+		let inputs = read_params(&signature[params_start..params_end])?;
+
+		let rest = signature[params_end..].trim_start();
+		let outputs = if rest.is_empty() {
+			vec![]
+		} else if let Some(outputs) = rest.strip_prefix(':').or_else(|| rest.strip_prefix("returns")) {
+			read_params(outputs.trim_start())?
+		} else {
+			return Err(invalid());
+		};
+
+		Ok((name.to_owned(), inputs, outputs))
+	}
+}
+
+/// Yields the characters of `s` with their positions and the number of
+/// parentheses enclosing them, counting a parenthesis as enclosed by itself.
+fn with_paren_depth(s: &str) -> impl Iterator<Item = (usize, char, usize)> + '_ {
+	let mut depth = 0usize;
+	s.char_indices().map(move |(pos, c)| match c {
+		'(' => {
+			depth += 1;
+			(pos, c, depth)
+		}
+		')' => {
+			let closed = depth;
+			depth = depth.saturating_sub(1);
+			(pos, c, closed)
+		}
+		_ => (pos, c, depth),
+	})
+}
+
+/// Returns the position of the parenthesis closing the one `s` starts with, or
+/// `None` if `s` doesn't start with `(` or it is never closed.
+pub(crate) fn closing_paren(s: &str) -> Option<usize> {
+	if !s.starts_with('(') {
+		return None;
+	}
+	with_paren_depth(s).find(|&(_, c, depth)| c == ')' && depth == 1).map(|(pos, _, _)| pos)
+}
+
+/// Splits `s` at every character matching `is_separator` outside of parentheses.
+pub(crate) fn split_top_level(s: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+	let mut parts = vec![];
+	let mut start = 0;
+	for (pos, c, depth) in with_paren_depth(s) {
+		if depth == 0 && is_separator(c) {
+			parts.push(&s[start..pos]);
+			start = pos + c.len_utf8();
+		}
+	}
+	parts.push(&s[start..]);
+	parts
+}
+
+/// Returns whether every `[` in the name is closed by a matching `]`.
+fn has_balanced_brackets(name: &str) -> bool {
+	let mut depth = 0usize;
+	for c in name.chars() {
+		match c {
+			'[' => depth += 1,
+			']' => match depth.checked_sub(1) {
+				Some(d) => depth = d,
+				None => return false,
+			},
+			_ => (),
+		}
+	}
+	depth == 0
+}
+
+/// Integer sizes range from 8 to 256 bits in steps of 8.
+fn is_valid_int_size(len: usize) -> bool {
+	(8..=256).step_by(8).any(|size| size == len)
 }
 
 #[cfg(test)]
@@ -176,7 +236,7 @@ mod tests {
 	use super::Reader;
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::ParamType;
+	use crate::{Error, ParamType};
 
 	#[test]
 	fn test_read_param() {
@@ -191,6 +251,108 @@ mod tests {
 		assert_eq!(Reader::read("uint32").unwrap(), ParamType::Uint(32));
 	}
 
+	#[test]
+	fn test_read_tuple_arrays_with_nested_tuples() {
+		for name in [
+			"((address,uint256,bytes)[],bytes32,bytes)",
+			"(((address,uint256)[],bytes)[],bytes32,bytes)",
+			"((address,(uint256,bytes)[])[],bytes32,bytes)",
+			"((address,(uint256,bytes))[],bytes32,bytes)",
+			"((uint256,(address,bytes)[],bool)[],bytes32,bytes)",
+			"((bytes32,(address,uint256)[])[3],bytes)",
+			"(((address)[])[],bytes)",
+			"()",
+		] {
+			assert_eq!(Reader::read(name).unwrap().to_string(), name);
+		}
+
+		assert_eq!(
+			Reader::read("((address,(uint256,bytes)[])[],bytes32,bytes)").unwrap(),
+			ParamType::Tuple(vec![
+				ParamType::Array(Box::new(ParamType::Tuple(vec![
+					ParamType::Address,
+					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Bytes]))),
+				]))),
+				ParamType::FixedBytes(32),
+				ParamType::Bytes,
+			])
+		);
+	}
+
+	#[test]
+	fn test_read_invalid_tuples() {
+		for name in ["(uint256,)", "(,uint256)", "(uint256))", "((uint256)", "(uint256)(bool)", "([uint256),bool)"] {
+			assert!(matches!(Reader::read(name), Err(Error::InvalidName(_))), "{}", name);
+		}
+	}
+
+	#[test]
+	fn test_read_invalid_arrays() {
+		for name in
+			["[]", "]", "uint256]", "[2]", "uint256[[2]", "uint256[2]]", "uint256[+2]", "uint256[2x]", "(bool)[[]"]
+		{
+			assert!(matches!(Reader::read(name), Err(Error::InvalidName(_))), "{}", name);
+		}
+		assert_eq!(
+			Reader::read("uint256[2][]").unwrap(),
+			ParamType::Array(Box::new(ParamType::FixedArray(Box::new(ParamType::Uint(256)), 2)))
+		);
+	}
+
+	#[test]
+	fn test_read_function_signature() {
+		let transfer = ("transfer".to_owned(), vec![ParamType::Address, ParamType::Uint(256)], vec![]);
+		assert_eq!(Reader::read_function_signature("transfer(address,uint256)").unwrap(), transfer);
+
+		let balance_of = ("balanceOf".to_owned(), vec![ParamType::Address], vec![ParamType::Uint(256)]);
+		assert_eq!(Reader::read_function_signature("balanceOf(address):(uint256)").unwrap(), balance_of);
+		assert_eq!(Reader::read_function_signature("balanceOf(address) returns (uint256)").unwrap(), balance_of);
+		assert_eq!(Reader::read_function_signature("balanceOf(address)returns(uint256)").unwrap(), balance_of);
+
+		assert_eq!(
+			Reader::read_function_signature("submit((address,uint256)[],bytes):(bool,string)").unwrap(),
+			(
+				"submit".to_owned(),
+				vec![
+					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]))),
+					ParamType::Bytes
+				],
+				vec![ParamType::Bool, ParamType::String]
+			)
+		);
+		assert_eq!(Reader::read_function_signature("f()").unwrap(), ("f".to_owned(), vec![], vec![]));
+
+		for signature in ["transfer", "(address)", "transfer(address", "f(uint256)[]", "f(uint256) (bool)", "f():bool"]
+		{
+			assert!(matches!(Reader::read_function_signature(signature), Err(Error::InvalidName(_))), "{}", signature);
+		}
+	}
+
+	#[test]
+	fn test_read_invalid_sizes() {
+		for name in
+			["uint0", "int0", "uint7", "int12", "uint264", "int512", "bytes0", "bytes33", "bytes0[]", "(uint256,uint9)"]
+		{
+			assert!(matches!(Reader::read(name), Err(Error::InvalidName(_))), "{}", name);
+		}
+		assert_eq!(Reader::read("uint8").unwrap(), ParamType::Uint(8));
+		assert_eq!(Reader::read("int256").unwrap(), ParamType::Int(256));
+		assert_eq!(Reader::read("bytes1").unwrap(), ParamType::FixedBytes(1));
+	}
+
+	#[test]
+	fn test_read_aliases_are_canonical() {
+		use crate::short_signature;
+
+		assert_eq!(Reader::read("uint").unwrap(), Reader::read("uint256").unwrap());
+		assert_eq!(Reader::read("int").unwrap(), Reader::read("int256").unwrap());
+		assert_eq!(Reader::read("(uint,int[])").unwrap(), Reader::read("(uint256,int256[])").unwrap());
+
+		let alias = [Reader::read("uint").unwrap()];
+		let canonical = [Reader::read("uint256").unwrap()];
+		assert_eq!(short_signature("transfer", &alias), short_signature("transfer", &canonical));
+	}
+
 	#[test]
 	fn test_read_array_param() {
 		assert_eq!(Reader::read("address[]").unwrap(), ParamType::Array(Box::new(ParamType::Address)));
@@ -288,4 +450,18 @@ mod tests {
 
 		assert_eq!(abi, Writer::write(&param));
 	}
+
+	#[test]
+	fn test_paren_scanning() {
+		use super::{closing_paren, split_top_level};
+
+		assert_eq!(closing_paren("(a,(b,c))[]"), Some(8));
+		assert_eq!(closing_paren("()"), Some(1));
+		assert_eq!(closing_paren("(a,(b)"), None);
+		assert_eq!(closing_paren("a()"), None);
+
+		assert_eq!(split_top_level("a,(b,c)[],d", |c| c == ','), vec!["a", "(b,c)[]", "d"]);
+		assert_eq!(split_top_level("(a b) c", char::is_whitespace), vec!["(a b)", "c"]);
+		assert_eq!(split_top_level("", |c| c == ','), vec![""]);
+	}
 }
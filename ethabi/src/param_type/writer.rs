@@ -6,9 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use alloc::collections::{BTreeMap, BTreeSet};
+
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::ParamType;
+use crate::{ParamType, TupleParam};
 
 /// Output formatter for param type.
 pub struct Writer;
@@ -53,12 +55,149 @@ impl Writer {
 	}
 }
 
+/// Renders a Solidity `struct` definition named `name` with the given named
+/// components, one field per line, e.g. the components of a tuple
+/// [`Param`](crate::Param).
+///
+/// Tuple components are rendered as nested structs, defined before the structs
+/// using them and only once per name. A nested struct is named after its
+/// `internal_type`, e.g. `Maker` for `struct Order.Maker[]`, and its fields
+/// after the `components` of the tuple param. If a struct is used by several
+/// fields, the first definition with named components is rendered. Without an
+/// internal type, it is named after its parent and field, e.g. `OrderMaker`
+/// for the `maker` field of `Order`, and components without a name are named
+/// by their index, `_0`, `_1`, and so on.
+///
+/// ```
+/// use ethabi::{param_type::render_solidity_struct, ParamType, TupleParam};
+///
+/// let param = |name: &str, kind| TupleParam { name: Some(name.to_owned()), kind, internal_type: None, components: vec![] };
+/// let components = [param("to", ParamType::Address), param("value", ParamType::Uint(256))];
+/// assert_eq!(render_solidity_struct("Transfer", &components), "struct Transfer {\n    address to;\n    uint256 value;\n}\n");
+/// ```
+pub fn render_solidity_struct(name: &str, components: &[TupleParam]) -> String {
+	let mut definitions = BTreeMap::new();
+	definitions.insert(name.to_owned(), components.to_vec());
+	collect_structs(&mut definitions, name, components);
+
+	let mut out = String::new();
+	write_solidity_struct(&mut out, &mut BTreeSet::new(), &definitions, name);
+	out
+}
+
+/// Adds the definitions of all structs nested in the struct `name` to
+/// `definitions`, replacing definitions without component names.
+fn collect_structs(definitions: &mut BTreeMap<String, Vec<TupleParam>>, name: &str, components: &[TupleParam]) {
+	for (index, param) in components.iter().enumerate() {
+		if let Some((struct_name, nested)) = nested_struct(name, &field_name(index, param), param) {
+			if definitions.get(&struct_name).is_none_or(|existing| !has_names(existing) && has_names(&nested)) {
+				definitions.insert(struct_name.clone(), nested.clone());
+			}
+			collect_structs(definitions, &struct_name, &nested);
+		}
+	}
+}
+
+fn write_solidity_struct(
+	out: &mut String,
+	emitted: &mut BTreeSet<String>,
+	definitions: &BTreeMap<String, Vec<TupleParam>>,
+	name: &str,
+) {
+	if !emitted.insert(name.to_owned()) {
+		return;
+	}
+
+	let fields = definitions[name]
+		.iter()
+		.enumerate()
+		.map(|(index, param)| {
+			let field = field_name(index, param);
+			let kind = match nested_struct(name, &field, param) {
+				Some((struct_name, _)) => {
+					write_solidity_struct(out, emitted, definitions, &struct_name);
+					solidity_type(&param.kind, &struct_name)
+				}
+				None => Writer::write(&param.kind),
+			};
+			format!("    {kind} {field};\n")
+		})
+		.collect::<String>();
+
+	if !out.is_empty() {
+		out.push('\n');
+	}
+	out.push_str(&format!("struct {name} {{\n{fields}}}\n"));
+}
+
+fn field_name(index: usize, param: &TupleParam) -> String {
+	match param.name.as_deref() {
+		Some(field) if !field.is_empty() => field.to_owned(),
+		_ => format!("_{index}"),
+	}
+}
+
+fn has_names(components: &[TupleParam]) -> bool {
+	components.iter().any(|param| param.name.as_deref().is_some_and(|name| !name.is_empty()))
+}
+
+/// Returns the name and components of the struct used by the field `field` of
+/// the struct `parent`, if the type of the field is a tuple or an array of
+/// tuples.
+fn nested_struct(parent: &str, field: &str, param: &TupleParam) -> Option<(String, Vec<TupleParam>)> {
+	let mut kind = &param.kind;
+	while let ParamType::Array(inner) | ParamType::FixedArray(inner, _) = kind {
+		kind = inner;
+	}
+	let params = match kind {
+		ParamType::Tuple(params) => params,
+		_ => return None,
+	};
+
+	let name = match param.internal_type.as_deref().and_then(struct_name) {
+		Some(name) => name.to_owned(),
+		None => {
+			let mut chars = field.chars();
+			let capitalized: String =
+				chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default();
+			format!("{parent}{capitalized}")
+		}
+	};
+	let components = if param.components.len() == params.len() {
+		param.components.clone()
+	} else {
+		params
+			.iter()
+			.map(|kind| TupleParam { name: None, kind: kind.clone(), internal_type: None, components: vec![] })
+			.collect()
+	};
+	Some((name, components))
+}
+
+/// Returns the name of the struct given by an internal type such as
+/// `struct Order.Maker[]`, without the contract and array suffixes.
+fn struct_name(internal_type: &str) -> Option<&str> {
+	let name = internal_type.strip_prefix("struct ")?.split('[').next()?;
+	name.rsplit('.').next().filter(|name| !name.is_empty())
+}
+
+/// Returns the Solidity type of `kind`, with the tuple it contains, if any,
+/// named `struct_name`.
+fn solidity_type(kind: &ParamType, struct_name: &str) -> String {
+	match kind {
+		ParamType::Tuple(_) => struct_name.to_owned(),
+		ParamType::Array(param) => format!("{}[]", solidity_type(param, struct_name)),
+		ParamType::FixedArray(param, len) => format!("{}[{len}]", solidity_type(param, struct_name)),
+		_ => Writer::write(kind),
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::Writer;
+	use super::{render_solidity_struct, Writer};
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::ParamType;
+	use crate::{ParamType, TupleParam};
 
 	#[test]
 	fn test_write_param() {
@@ -94,4 +233,80 @@ mod tests {
 			"tuple[]".to_owned()
 		);
 	}
+
+	#[test]
+	fn test_render_solidity_struct() {
+		let param =
+			|name: &str, kind: ParamType, internal_type: Option<&str>, components: Vec<TupleParam>| TupleParam {
+				name: Some(name.to_owned()),
+				kind,
+				internal_type: internal_type.map(ToOwned::to_owned),
+				components,
+			};
+
+		let fill = ParamType::Tuple(vec![
+			ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Bool])), 2),
+			ParamType::Bytes,
+		]);
+		let components = [
+			param(
+				"maker",
+				ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(96)]),
+				Some("struct Exchange.Maker"),
+				vec![
+					param("account", ParamType::Address, None, vec![]),
+					param("nonce", ParamType::Uint(96), None, vec![]),
+				],
+			),
+			param("fills", ParamType::Array(Box::new(fill)), None, vec![]),
+			param(
+				"taker",
+				ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(96)]),
+				Some("struct Maker"),
+				vec![],
+			),
+			param("", ParamType::Array(Box::new(ParamType::String)), None, vec![]),
+		];
+
+		// the components of `fills` are unknown, `Maker` is only defined once
+		let expected = "\
+struct Maker {
+    address account;
+    uint96 nonce;
+}
+
+struct OrderFills_0 {
+    bool _0;
+}
+
+struct OrderFills {
+    OrderFills_0[2] _0;
+    bytes _1;
+}
+
+struct Order {
+    Maker maker;
+    OrderFills[] fills;
+    Maker taker;
+    string[] _3;
+}
+";
+		assert_eq!(render_solidity_struct("Order", &components), expected);
+
+		// a later definition with component names is preferred
+		let maker =
+			|components| param("maker", ParamType::Tuple(vec![ParamType::Address]), Some("struct Maker"), components);
+		let components = [maker(vec![]), maker(vec![param("account", ParamType::Address, None, vec![])])];
+		let expected = "\
+struct Maker {
+    address account;
+}
+
+struct Order {
+    Maker maker;
+    Maker maker;
+}
+";
+		assert_eq!(render_solidity_struct("Order", &components), expected);
+	}
 }
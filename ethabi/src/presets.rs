@@ -0,0 +1,103 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Call data for common contract standards without loading an ABI, enabled by
+//! the `presets` feature.
+
+use crate::{encode, signature::short_signature, Bytes, ParamType, Token};
+
+/// Encodes a call of the function `name` with arguments `tokens` of types `params`.
+fn encode_call(name: &str, params: &[ParamType], tokens: &[Token]) -> Bytes {
+	short_signature(name, params).iter().copied().chain(encode(tokens)).collect()
+}
+
+/// Call data for functions of the ERC-20 token standard.
+///
+/// ```
+/// use ethabi::{presets::erc20, Address, Uint};
+///
+/// let data = erc20::transfer(Address::repeat_byte(0x11), Uint::from(1000));
+/// assert_eq!(&data[..4], &[0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+pub mod erc20 {
+	use super::encode_call;
+	use crate::{Address, Bytes, ParamType, Token, Uint};
+
+	/// `transfer(address to, uint256 amount)`
+	pub fn transfer(to: Address, amount: Uint) -> Bytes {
+		encode_call("transfer", &[ParamType::Address, ParamType::Uint(256)], &[Token::Address(to), Token::Uint(amount)])
+	}
+
+	/// `transferFrom(address from, address to, uint256 amount)`
+	pub fn transfer_from(from: Address, to: Address, amount: Uint) -> Bytes {
+		encode_call(
+			"transferFrom",
+			&[ParamType::Address, ParamType::Address, ParamType::Uint(256)],
+			&[Token::Address(from), Token::Address(to), Token::Uint(amount)],
+		)
+	}
+
+	/// `approve(address spender, uint256 amount)`
+	pub fn approve(spender: Address, amount: Uint) -> Bytes {
+		encode_call(
+			"approve",
+			&[ParamType::Address, ParamType::Uint(256)],
+			&[Token::Address(spender), Token::Uint(amount)],
+		)
+	}
+
+	/// `balanceOf(address owner)`
+	pub fn balance_of(owner: Address) -> Bytes {
+		encode_call("balanceOf", &[ParamType::Address], &[Token::Address(owner)])
+	}
+
+	/// `allowance(address owner, address spender)`
+	pub fn allowance(owner: Address, spender: Address) -> Bytes {
+		encode_call(
+			"allowance",
+			&[ParamType::Address, ParamType::Address],
+			&[Token::Address(owner), Token::Address(spender)],
+		)
+	}
+
+	/// `totalSupply()`
+	pub fn total_supply() -> Bytes {
+		encode_call("totalSupply", &[], &[])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::erc20;
+	use crate::{Address, Uint};
+
+	#[test]
+	fn erc20_calls() {
+		let alice = Address::repeat_byte(0x11);
+		let bob = Address::repeat_byte(0x22);
+
+		assert_eq!(
+			erc20::transfer(bob, Uint::from(1000)),
+			hex!(
+				"
+				a9059cbb
+				0000000000000000000000002222222222222222222222222222222222222222
+				00000000000000000000000000000000000000000000000000000000000003e8
+			"
+			)
+		);
+		assert_eq!(&erc20::transfer_from(alice, bob, Uint::zero())[..4], &hex!("23b872dd"));
+		assert_eq!(&erc20::approve(bob, Uint::MAX)[..4], &hex!("095ea7b3"));
+		assert_eq!(&erc20::balance_of(alice)[..4], &hex!("70a08231"));
+		assert_eq!(&erc20::allowance(alice, bob)[..4], &hex!("dd62ed3e"));
+		assert_eq!(erc20::total_supply(), hex!("18160ddd"));
+		assert_eq!(erc20::allowance(alice, bob).len(), 4 + 2 * 32);
+	}
+}
@@ -16,6 +16,16 @@ use crate::{
 };
 
 /// Returns the first four bytes of the Keccak-256 hash of the signature of the given params
+///
+/// This is the selector of a function with the given name and input types, computed without
+/// constructing a [`Function`](crate::Function).
+///
+/// ```
+/// use ethabi::{short_signature, ParamType};
+///
+/// let selector = short_signature("transfer", &[ParamType::Address, ParamType::Uint(256)]);
+/// assert_eq!(selector, [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
 pub fn short_signature(name: &str, params: &[ParamType]) -> [u8; 4] {
 	let mut result = [0u8; 4];
 	fill_signature(name, params, &mut result);
@@ -23,6 +33,16 @@ pub fn short_signature(name: &str, params: &[ParamType]) -> [u8; 4] {
 }
 
 /// Returns the full Keccak-256 hash of the signature of the given params
+///
+/// This is the first topic of logs emitted by a non anonymous event with the given name and
+/// input types, computed without constructing an [`Event`](crate::Event).
+///
+/// ```
+/// use ethabi::{long_signature, ParamType};
+///
+/// let topic = long_signature("Transfer", &[ParamType::Address, ParamType::Address, ParamType::Uint(256)]);
+/// assert_eq!(format!("{topic:x}"), "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// ```
 pub fn long_signature(name: &str, params: &[ParamType]) -> Hash {
 	let mut result = [0u8; 32];
 	fill_signature(name, params, &mut result);
@@ -30,16 +50,21 @@ pub fn long_signature(name: &str, params: &[ParamType]) -> Hash {
 }
 
 fn fill_signature(name: &str, params: &[ParamType], result: &mut [u8]) {
-	let types = params.iter().map(Writer::write).collect::<Vec<String>>().join(",");
-
-	let data: Vec<u8> = From::from(format!("{name}({types})").as_str());
+	let data: Vec<u8> = From::from(canonical_signature(name, params).as_str());
 
 	result.copy_from_slice(&Keccak256::digest(data)[..result.len()])
 }
 
+/// Returns the signature hashed to compute selectors and event topics, i.e. the
+/// name followed by the comma separated types of the params in parentheses.
+pub(crate) fn canonical_signature<'a>(name: &str, params: impl IntoIterator<Item = &'a ParamType>) -> String {
+	let types = params.into_iter().map(Writer::write).collect::<Vec<String>>().join(",");
+	format!("{name}({types})")
+}
+
 #[cfg(test)]
 mod tests {
-	use super::short_signature;
+	use super::{long_signature, short_signature};
 	use crate::ParamType;
 	use hex_literal::hex;
 
@@ -47,4 +72,14 @@ mod tests {
 	fn test_signature() {
 		assert_eq!(hex!("cdcd77c0"), short_signature("baz", &[ParamType::Uint(32), ParamType::Bool]));
 	}
+
+	#[test]
+	fn test_long_signature() {
+		let signature = long_signature("baz", &[ParamType::Uint(32), ParamType::Bool]);
+		assert_eq!(hex!("cdcd77c0"), signature[..4]);
+		assert_eq!(
+			hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+			long_signature("Transfer", &[ParamType::Address, ParamType::Address, ParamType::Uint(256)]).0
+		);
+	}
 }
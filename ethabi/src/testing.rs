@@ -0,0 +1,65 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers for testing ABI encodings, enabled by the `test-helpers` feature.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{decode, encode, ParamType, Token};
+
+/// Asserts that `tokens` encode to `expected_hex` and that `expected_hex`
+/// decodes back to `tokens` when decoded as `types`.
+///
+/// `expected_hex` may be `0x` prefixed and contain whitespace, so that
+/// encodings can be written one word per line.
+///
+/// # Panics
+///
+/// Panics if `expected_hex` isn't valid hex, or if encoding or decoding
+/// doesn't give the expected result.
+pub fn assert_encode_decode(types: &[ParamType], tokens: &[Token], expected_hex: &str) {
+	let expected_hex: String = expected_hex.chars().filter(|c| !c.is_whitespace()).collect();
+	let expected = hex::decode(expected_hex.strip_prefix("0x").unwrap_or(&expected_hex))
+		.unwrap_or_else(|err| panic!("invalid expected hex: {err}"));
+
+	assert_eq!(hex::encode(encode(tokens)), hex::encode(&expected), "encoded tokens differ");
+	let decoded = decode(types, &expected).unwrap_or_else(|err| panic!("cannot decode expected data: {err:?}"));
+	assert_eq!(decoded, tokens, "decoded tokens differ");
+}
+
+#[cfg(test)]
+mod tests {
+	use super::assert_encode_decode;
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
+	use crate::{ParamType, Token};
+
+	#[test]
+	fn encode_decode() {
+		assert_encode_decode(
+			&[ParamType::Bool, ParamType::Bytes],
+			&[Token::Bool(true), Token::Bytes(vec![0x12, 0x34])],
+			"0x
+			0000000000000000000000000000000000000000000000000000000000000001
+			0000000000000000000000000000000000000000000000000000000000000040
+			0000000000000000000000000000000000000000000000000000000000000002
+			1234000000000000000000000000000000000000000000000000000000000000
+			",
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "encoded tokens differ")]
+	fn encode_mismatch() {
+		assert_encode_decode(
+			&[ParamType::Bool],
+			&[Token::Bool(false)],
+			"0000000000000000000000000000000000000000000000000000000000000001",
+		);
+	}
+}
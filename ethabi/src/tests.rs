@@ -17,6 +17,8 @@ use serde_json::Value;
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
+#[cfg(feature = "serde")]
+use crate::TupleParam;
 use crate::{decode, encode, ParamType, Token};
 
 #[cfg(feature = "serde")]
@@ -36,6 +38,12 @@ where
 	assert_eq!(canon, &de);
 }
 
+/// Builds a tuple component without internal type, as deserialized from an ABI.
+#[cfg(feature = "serde")]
+pub(crate) fn component(name: Option<&str>, kind: ParamType, components: Vec<TupleParam>) -> TupleParam {
+	TupleParam { name: name.map(ToOwned::to_owned), kind, internal_type: None, components }
+}
+
 macro_rules! test_encode_decode {
 	(name: $name:tt, types: $types:expr, tokens: $tokens:expr, data: $data:tt) => {
 		paste::item! {
@@ -606,6 +614,51 @@ test_encode_decode! {
 	"
 }
 
+// test top level dynamic array of static tuples
+test_encode_decode! {
+	name: dynamic_array_of_static_tuples,
+	types: [
+		ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)])))
+	],
+	tokens: [
+		Token::Array(vec![
+			Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Uint(1.into())]),
+			Token::Tuple(vec![Token::Address([0x22u8; 20].into()), Token::Uint(2.into())]),
+		])
+	],
+	data: "
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000001111111111111111111111111111111111111111
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000002222222222222222222222222222222222222222
+		0000000000000000000000000000000000000000000000000000000000000002
+	"
+}
+test_encode_decode! {
+	name: dynamic_array_of_static_tuples_between_static_params,
+	types: [
+		ParamType::Bool,
+		ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]))),
+		ParamType::Uint(256),
+	],
+	tokens: [
+		Token::Bool(true),
+		Token::Array(vec![
+			Token::Tuple(vec![Token::Address([0x11u8; 20].into()), Token::Uint(1.into())]),
+		]),
+		Token::Uint(3.into()),
+	],
+	data: "
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000060
+		0000000000000000000000000000000000000000000000000000000000000003
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000001111111111111111111111111111111111111111
+		0000000000000000000000000000000000000000000000000000000000000001
+	"
+}
+
 // comprehensive test
 test_encode_decode! {
 	name: comprehensive_test,
@@ -14,8 +14,31 @@ use crate::{
 use std::borrow::Cow;
 
 use once_cell::sync::Lazy;
-static RE: Lazy<regex::Regex> =
-	Lazy::new(|| regex::Regex::new(r"^([0-9]+)(\.[0-9]+)?\s*(ether|gwei|nanoether|nano|wei)$").expect("invalid regex"));
+static RE: Lazy<regex::Regex> = Lazy::new(|| {
+	regex::Regex::new(
+		r"^([0-9]+)(\.[0-9]+)?\s*(ether|milliether|finney|microether|szabo|gwei|nanoether|nano|mwei|lovelace|kwei|babbage|wei)$",
+	)
+	.expect("invalid regex")
+});
+
+/// Removes underscores separating decimal digits, as in `1_000_000`. Hex
+/// quantities and underscores not surrounded by digits are left as is, so that
+/// parsing them fails.
+fn strip_digit_separators(value: &str) -> Cow<'_, str> {
+	if !value.contains('_') || value.trim_start_matches('-').starts_with("0x") {
+		return Cow::Borrowed(value);
+	}
+
+	let bytes = value.as_bytes();
+	let is_digit_at = |index: Option<usize>| index.and_then(|index| bytes.get(index)).is_some_and(u8::is_ascii_digit);
+	Cow::Owned(
+		value
+			.char_indices()
+			.filter(|&(index, c)| c != '_' || !(is_digit_at(index.checked_sub(1)) && is_digit_at(Some(index + 1))))
+			.map(|(_, c)| c)
+			.collect(),
+	)
+}
 
 /// Tries to parse string as a token. Does not require string to clearly represent the value.
 pub struct LenientTokenizer;
@@ -46,6 +69,7 @@ impl Tokenizer for LenientTokenizer {
 		if result.is_ok() {
 			return result;
 		}
+		let value = &*strip_digit_separators(value);
 
 		// Tries to parse it as is first. If it fails, tries to check for
 		// expectable units with the following format: 'Number[Spaces]Unit'.
@@ -63,7 +87,11 @@ impl Tokenizer for LenientTokenizer {
 
 						let units = Uint::from(match units.to_lowercase().as_str() {
 							"ether" => 18,
+							"finney" | "milliether" => 15,
+							"szabo" | "microether" => 12,
 							"gwei" | "nano" | "nanoether" => 9,
+							"mwei" | "lovelace" => 6,
+							"kwei" | "babbage" => 3,
 							"wei" => 0,
 							_ => return Err(dec_error.into()),
 						});
@@ -101,6 +129,7 @@ impl Tokenizer for LenientTokenizer {
 		if result.is_ok() {
 			return result;
 		}
+		let value = &*strip_digit_separators(value);
 
 		let abs = Uint::from_dec_str(value.trim_start_matches('-'))?;
 		let max = Uint::max_value() / 2;
@@ -127,7 +156,7 @@ mod tests {
 
 	use crate::{
 		errors::Error,
-		token::{LenientTokenizer, Token, Tokenizer},
+		token::{LenientTokenizer, StrictTokenizer, Token, Tokenizer},
 		ParamType, Uint,
 	};
 
@@ -143,6 +172,37 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn tokenize_uint_hex() {
+		assert_eq!(LenientTokenizer::tokenize(&ParamType::Uint(256), "0x1f").unwrap(), Token::Uint(Uint::from(31)));
+		assert_eq!(LenientTokenizer::tokenize(&ParamType::Uint(256), "31").unwrap(), Token::Uint(Uint::from(31)));
+		assert_eq!(LenientTokenizer::tokenize(&ParamType::Int(256), "0x1f").unwrap(), Token::Int(Uint::from(31)));
+	}
+
+	#[test]
+	fn tokenize_digit_separators_and_leading_zeros() {
+		let uint = |value| LenientTokenizer::tokenize(&ParamType::Uint(256), value);
+		let int = |value| LenientTokenizer::tokenize(&ParamType::Int(256), value);
+
+		assert_eq!(uint("1_000").unwrap(), Token::Uint(Uint::from(1000)));
+		assert_eq!(uint("1_000_000").unwrap(), Token::Uint(Uint::from(1_000_000)));
+		assert_eq!(uint("1_000 gwei").unwrap(), Token::Uint(Uint::from(1_000_000_000_000u64)));
+		assert_eq!(uint("007").unwrap(), Token::Uint(Uint::from(7)));
+		assert_eq!(uint("00_1").unwrap(), Token::Uint(Uint::from(1)));
+		assert_eq!(int("-1_000").unwrap(), Token::Int(!Uint::from(1000) + 1));
+		assert_eq!(int("-007").unwrap(), Token::Int(!Uint::from(7) + 1));
+
+		// only single underscores between decimal digits are separators
+		for value in ["_1", "1_", "1__000", "1_.5 ether", "0x0_1", "0x_1"] {
+			assert!(uint(value).is_err(), "{value}");
+		}
+		assert!(int("-_1").is_err());
+
+		// the strict tokenizer doesn't accept separators
+		assert!(StrictTokenizer::tokenize(&ParamType::Uint(256), "0x1_000").is_err());
+		assert!(StrictTokenizer::tokenize(&ParamType::Int(256), "1_000").is_err());
+	}
+
 	#[test]
 	fn tokenize_uint_wei() {
 		assert_eq!(LenientTokenizer::tokenize(&ParamType::Uint(256), "1wei").unwrap(), Token::Uint(Uint::from(1)));
@@ -201,6 +261,30 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn tokenize_uint_other_units() {
+		let units = [
+			("1 kwei", "1000"),
+			("1babbage", "1000"),
+			("1 mwei", "1000000"),
+			("1lovelace", "1000000"),
+			("1 szabo", "1000000000000"),
+			("1microether", "1000000000000"),
+			("1 finney", "1000000000000000"),
+			("1milliether", "1000000000000000"),
+			("1.5 finney", "1500000000000000"),
+		];
+		for (value, expected) in units {
+			assert_eq!(
+				LenientTokenizer::tokenize(&ParamType::Uint(256), value).unwrap(),
+				Token::Uint(Uint::from_dec_str(expected).unwrap())
+			);
+		}
+
+		// 0.1 wei
+		assert!(LenientTokenizer::tokenize(&ParamType::Uint(256), "0.0001 kwei").is_err());
+	}
+
 	#[test]
 	fn tokenize_uint_array_ether() {
 		assert_eq!(
@@ -18,8 +18,13 @@ mod strict;
 #[cfg(feature = "full-serde")]
 pub use strict::StrictTokenizer;
 
+#[cfg(feature = "full-serde")]
+mod named;
+#[cfg(feature = "full-serde")]
+pub use named::{NamedParamType, NamedToken};
+
 mod token;
-pub use token::Token;
+pub use token::{DisplayToken, Token};
 
 #[cfg(all(feature = "serde", not(feature = "std")))]
 use crate::no_std_prelude::*;
@@ -27,7 +32,7 @@ use crate::no_std_prelude::*;
 use core::cmp::Ordering::{Equal, Less};
 
 #[cfg(feature = "serde")]
-use crate::{Error, ParamType};
+use crate::{no_std_prelude::Cow, Error, ParamType};
 
 /// This trait should be used to parse string values as tokens.
 #[cfg(feature = "serde")]
@@ -252,9 +257,52 @@ pub trait Tokenizer {
 		Ok(result)
 	}
 
+	/// Tries to parse a value as an array of fixed size byte strings.
+	///
+	/// Besides the `[0x..,0x..]` form accepted by [`Tokenizer::tokenize`], this accepts
+	/// the elements concatenated into a single hex string, which is split into elements
+	/// of the size given by `param`. Fails if `param` isn't an array of `bytesN`.
+	fn tokenize_packed_array(value: &str, param: &ParamType) -> Result<Token, Error> {
+		let (element, len) = match *param {
+			ParamType::Array(ref element) => (element, None),
+			ParamType::FixedArray(ref element, len) => (element, Some(len)),
+			_ => return Err(Error::InvalidData),
+		};
+		let size = match **element {
+			ParamType::FixedBytes(size) if size > 0 => size,
+			_ => return Err(Error::InvalidData),
+		};
+		if value.starts_with('[') {
+			return Self::tokenize(param, value);
+		}
+
+		let bytes = Self::tokenize_bytes(value.strip_prefix("0x").unwrap_or(value))?;
+		let chunks = bytes.chunks_exact(size);
+		if !chunks.remainder().is_empty() {
+			return Err(Error::InvalidData);
+		}
+		let tokens: Vec<_> = chunks.map(|chunk| Token::FixedBytes(chunk.to_vec())).collect();
+		match len {
+			None => Ok(Token::Array(tokens)),
+			Some(len) if tokens.len() == len => Ok(Token::FixedArray(tokens)),
+			Some(_) => Err(Error::InvalidData),
+		}
+	}
+
 	/// Tries to parse a value as an address.
 	fn tokenize_address(value: &str) -> Result<[u8; 20], Error>;
 
+	/// Tries to parse a value as an address like [`tokenize_address`](Tokenizer::tokenize_address),
+	/// but rejects mixed case values whose EIP-55 checksum doesn't match, which
+	/// most likely contain a typo. All lowercase and all uppercase values carry
+	/// no checksum and are accepted.
+	fn tokenize_address_checked(value: &str) -> Result<[u8; 20], Error> {
+		if !crate::has_valid_checksum(value) {
+			return Err(Error::Other(Cow::Owned(format!("invalid address checksum: {value}"))));
+		}
+		Self::tokenize_address(value)
+	}
+
 	/// Tries to parse a value as a string.
 	fn tokenize_string(value: &str) -> Result<String, Error>;
 
@@ -276,9 +324,54 @@ pub trait Tokenizer {
 
 #[cfg(all(test, feature = "full-serde"))]
 mod test {
-	use super::{LenientTokenizer, ParamType, Tokenizer};
+	use hex_literal::hex;
+
+	use super::{LenientTokenizer, ParamType, StrictTokenizer, Tokenizer};
 	use crate::Token;
 
+	#[test]
+	fn tokenize_address_checked() {
+		let address = hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+
+		for value in ["5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"] {
+			assert_eq!(StrictTokenizer::tokenize_address_checked(value).unwrap(), address);
+		}
+		assert!(StrictTokenizer::tokenize_address_checked("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_err());
+		assert!(StrictTokenizer::tokenize_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_ok());
+	}
+
+	#[test]
+	fn tokenize_packed_array() {
+		let bytes32_array = ParamType::Array(Box::new(ParamType::FixedBytes(32)));
+		let packed = format!("0x{}{}", "11".repeat(32), "22".repeat(32));
+		let expected = Token::Array(vec![Token::FixedBytes(vec![0x11; 32]), Token::FixedBytes(vec![0x22; 32])]);
+
+		assert_eq!(LenientTokenizer::tokenize_packed_array(&packed, &bytes32_array).unwrap(), expected);
+		assert_eq!(LenientTokenizer::tokenize_packed_array(&packed[2..], &bytes32_array).unwrap(), expected);
+		let list = format!("[0x{},0x{}]", "11".repeat(32), "22".repeat(32));
+		assert_eq!(LenientTokenizer::tokenize_packed_array(&list, &bytes32_array).unwrap(), expected);
+		assert_eq!(LenientTokenizer::tokenize_packed_array("0x", &bytes32_array).unwrap(), Token::Array(vec![]));
+		assert_eq!(
+			LenientTokenizer::tokenize_packed_array(
+				"0x11112222",
+				&ParamType::FixedArray(Box::new(ParamType::FixedBytes(2)), 2)
+			)
+			.unwrap(),
+			Token::FixedArray(vec![Token::FixedBytes(vec![0x11; 2]), Token::FixedBytes(vec![0x22; 2])])
+		);
+
+		assert!(LenientTokenizer::tokenize_packed_array(
+			&packed[..66],
+			&ParamType::FixedArray(Box::new(ParamType::FixedBytes(32)), 2)
+		)
+		.is_err());
+		assert!(LenientTokenizer::tokenize_packed_array(&packed[..64], &bytes32_array).is_err());
+		assert!(LenientTokenizer::tokenize_packed_array(&packed, &ParamType::Array(Box::new(ParamType::Uint(256))))
+			.is_err());
+		// plain arrays keep requiring the list form
+		assert!(LenientTokenizer::tokenize(&bytes32_array, &packed).is_err());
+	}
+
 	#[test]
 	fn single_quoted_in_array_must_error() {
 		assert!(LenientTokenizer::tokenize_array("[1,\"0,false]", &ParamType::Bool).is_err());
@@ -0,0 +1,384 @@
+// Copyright 2015-2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Self describing JSON representation of decoded tokens.
+//!
+//! A [`NamedToken`] is serialized as a JSON object following the ABI conventions for parameters,
+//! with the decoded value added under `value`:
+//!
+//! ```json
+//! {
+//!   "name": "orders",
+//!   "type": "tuple[]",
+//!   "components": [
+//!     { "name": "maker", "type": "address" },
+//!     { "name": "amounts", "type": "uint256[]" }
+//!   ],
+//!   "value": [["0x1111111111111111111111111111111111111111", ["1", "2"]]]
+//! }
+//! ```
+//!
+//! `name` is always present and empty for unnamed params. It may be omitted when deserializing, so
+//! a plain `{ "type": "uint256", "value": "123" }` is a valid named token as well. `components` is
+//! only present when the type contains a tuple. Values are encoded as follows:
+//! - `address`, `bytes` and `bytesN` are `0x` prefixed hex strings,
+//! - `uintN` and `intN` are decimal strings, negative integers are prefixed with `-`,
+//! - `bool` and `string` are JSON booleans and strings,
+//! - arrays and tuples are JSON arrays, tuple values are in the order of their components.
+
+use serde::{de::Error as DeError, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{
+	param::{inner_tuple, inner_tuple_mut},
+	param_type::Writer,
+	Address, Error, Int, Param, ParamType, Token, Uint,
+};
+
+/// Name and type of a parameter, including the names of its tuple components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedParamType {
+	/// Param name.
+	pub name: String,
+	/// Param type.
+	pub kind: ParamType,
+	/// Components of the tuple contained in `kind`, empty if it contains none.
+	pub components: Vec<NamedParamType>,
+}
+
+impl NamedParamType {
+	/// Creates a named param type, tuple components are left unnamed.
+	pub fn new(name: impl Into<String>, kind: ParamType) -> Self {
+		let components = inner_tuple(&kind)
+			.map(|inner| inner.iter().map(|kind| NamedParamType::new("", kind.clone())).collect())
+			.unwrap_or_default();
+		NamedParamType { name: name.into(), kind, components }
+	}
+}
+
+impl From<&Param> for NamedParamType {
+	fn from(param: &Param) -> Self {
+		NamedParamType::new(param.name.clone(), param.kind.clone())
+	}
+}
+
+impl Serialize for NamedParamType {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut map = serializer.serialize_map(None)?;
+		self.serialize_entries(&mut map)?;
+		map.end()
+	}
+}
+
+impl NamedParamType {
+	fn serialize_entries<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+		map.serialize_entry("name", &self.name)?;
+		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
+		if inner_tuple(&self.kind).is_some() {
+			map.serialize_entry("components", &self.components)?;
+		}
+		Ok(())
+	}
+}
+
+impl<'a> Deserialize<'a> for NamedParamType {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'a>,
+	{
+		RawNamedParamType::deserialize(deserializer)?.try_into().map_err(D::Error::custom)
+	}
+}
+
+#[derive(Deserialize)]
+struct RawNamedParamType {
+	#[serde(default)]
+	name: String,
+	#[serde(rename = "type")]
+	kind: ParamType,
+	#[serde(default)]
+	components: Option<Vec<NamedParamType>>,
+}
+
+impl TryFrom<RawNamedParamType> for NamedParamType {
+	type Error = &'static str;
+
+	fn try_from(raw: RawNamedParamType) -> Result<Self, Self::Error> {
+		let RawNamedParamType { name, mut kind, components } = raw;
+		let components = match (inner_tuple_mut(&mut kind), components) {
+			(Some(inner), Some(components)) => {
+				inner.extend(components.iter().map(|component| component.kind.clone()));
+				components
+			}
+			(Some(_), None) => return Err("missing field `components`"),
+			(None, _) => vec![],
+		};
+		Ok(NamedParamType { name, kind, components })
+	}
+}
+
+/// A token together with the name and type of the parameter it belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedToken {
+	/// Name and type of the param.
+	pub param: NamedParamType,
+	/// Param value.
+	pub value: Token,
+}
+
+impl NamedToken {
+	/// Creates a named token, checking that the value matches the param type.
+	pub fn new(param: NamedParamType, value: Token) -> Result<Self, Error> {
+		if !value.type_check(&param.kind) {
+			return Err(Error::InvalidData);
+		}
+		Ok(NamedToken { param, value })
+	}
+
+	/// Creates a named token for an unnamed param of the given type.
+	pub fn unnamed(kind: ParamType, value: Token) -> Result<Self, Error> {
+		NamedToken::new(NamedParamType::new("", kind), value)
+	}
+}
+
+impl NamedToken {
+	/// Returns a view of the token which serializes like the token itself, but
+	/// with addresses in their EIP-55 checksummed form.
+	pub fn checksummed(&self) -> impl Serialize + '_ {
+		JsonToken { token: self, checksum: true }
+	}
+}
+
+impl Serialize for NamedToken {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		JsonToken { token: self, checksum: false }.serialize(serializer)
+	}
+}
+
+struct JsonToken<'a> {
+	token: &'a NamedToken,
+	checksum: bool,
+}
+
+impl Serialize for JsonToken<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut map = serializer.serialize_map(None)?;
+		self.token.param.serialize_entries(&mut map)?;
+		map.serialize_entry("value", &value_to_json(&self.token.value, self.checksum))?;
+		map.end()
+	}
+}
+
+impl<'a> Deserialize<'a> for NamedToken {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'a>,
+	{
+		#[derive(Deserialize)]
+		struct RawNamedToken {
+			#[serde(flatten)]
+			param: RawNamedParamType,
+			value: Value,
+		}
+
+		let raw = RawNamedToken::deserialize(deserializer)?;
+		let param = NamedParamType::try_from(raw.param).map_err(D::Error::custom)?;
+		let value = value_from_json(&param.kind, &raw.value)
+			.ok_or_else(|| D::Error::custom(format!("invalid value for type `{}`", param.kind)))?;
+		Ok(NamedToken { param, value })
+	}
+}
+
+fn value_to_json(token: &Token, checksum: bool) -> Value {
+	match token {
+		Token::Bool(b) => Value::Bool(*b),
+		Token::String(s) => Value::String(s.clone()),
+		Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+			Value::Array(tokens.iter().map(|token| value_to_json(token, checksum)).collect())
+		}
+		Token::Address(_) | Token::FixedBytes(_) | Token::Bytes(_) | Token::Uint(_) | Token::Int(_) => {
+			Value::String(token.display(checksum).to_string())
+		}
+	}
+}
+
+fn value_from_json(kind: &ParamType, value: &Value) -> Option<Token> {
+	let hex = |value: &Value| value.as_str().and_then(|s| s.strip_prefix("0x")).and_then(|s| hex::decode(s).ok());
+	fn values(value: &Value, len: Option<usize>) -> Option<&Vec<Value>> {
+		value.as_array().filter(|values| len.iter().all(|len| values.len() == *len))
+	}
+
+	let token = match kind {
+		ParamType::Address => Token::Address(Address::from_slice(&hex(value).filter(|bytes| bytes.len() == 20)?)),
+		ParamType::Bytes => Token::Bytes(hex(value)?),
+		ParamType::FixedBytes(len) => Token::FixedBytes(hex(value).filter(|bytes| bytes.len() == *len)?),
+		ParamType::Uint(_) => Token::Uint(Uint::from_dec_str(value.as_str()?).ok()?),
+		ParamType::Int(_) => {
+			let s = value.as_str()?;
+			let (negative, abs) = s.strip_prefix('-').map_or((false, s), |abs| (true, abs));
+			let abs = Int::from_dec_str(abs).ok()?;
+			match negative {
+				true if abs <= Int::one() << 255 => Token::Int((!abs).overflowing_add(Int::one()).0),
+				false if !abs.bit(255) => Token::Int(abs),
+				_ => return None,
+			}
+		}
+		ParamType::Bool => Token::Bool(value.as_bool()?),
+		ParamType::String => Token::String(value.as_str()?.to_owned()),
+		ParamType::Array(kind) => {
+			Token::Array(values(value, None)?.iter().map(|value| value_from_json(kind, value)).collect::<Option<_>>()?)
+		}
+		ParamType::FixedArray(kind, len) => Token::FixedArray(
+			values(value, Some(*len))?.iter().map(|value| value_from_json(kind, value)).collect::<Option<_>>()?,
+		),
+		ParamType::Tuple(kinds) => Token::Tuple(
+			values(value, Some(kinds.len()))?
+				.iter()
+				.zip(kinds)
+				.map(|(value, kind)| value_from_json(kind, value))
+				.collect::<Option<_>>()?,
+		),
+	};
+	Some(token)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{NamedParamType, NamedToken};
+	use crate::{ParamType, Token, Uint};
+	use serde_json::json;
+
+	fn orders() -> NamedToken {
+		let param = NamedParamType {
+			name: "orders".into(),
+			kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+				ParamType::Address,
+				ParamType::Array(Box::new(ParamType::Int(256))),
+				ParamType::Tuple(vec![ParamType::Bytes, ParamType::Bool]),
+			]))),
+			components: vec![
+				NamedParamType::new("maker", ParamType::Address),
+				NamedParamType::new("amounts", ParamType::Array(Box::new(ParamType::Int(256)))),
+				NamedParamType {
+					name: "extra".into(),
+					kind: ParamType::Tuple(vec![ParamType::Bytes, ParamType::Bool]),
+					components: vec![
+						NamedParamType::new("data", ParamType::Bytes),
+						NamedParamType::new("flag", ParamType::Bool),
+					],
+				},
+			],
+		};
+		let value = Token::Array(vec![Token::Tuple(vec![
+			Token::Address([0x11u8; 20].into()),
+			Token::Array(vec![Token::Int(Uint::from(256)), Token::Int(!Uint::from(2) + 1)]),
+			Token::Tuple(vec![Token::Bytes(vec![0x12, 0x34]), Token::Bool(true)]),
+		])]);
+		NamedToken::new(param, value).unwrap()
+	}
+
+	#[test]
+	fn named_token_serialization() {
+		let json = json!({
+			"name": "orders",
+			"type": "tuple[]",
+			"components": [
+				{ "name": "maker", "type": "address" },
+				{ "name": "amounts", "type": "int256[]" },
+				{
+					"name": "extra",
+					"type": "tuple",
+					"components": [
+						{ "name": "data", "type": "bytes" },
+						{ "name": "flag", "type": "bool" },
+					],
+				},
+			],
+			"value": [["0x1111111111111111111111111111111111111111", ["256", "-2"], ["0x1234", true]]],
+		});
+
+		assert_eq!(serde_json::to_value(orders()).unwrap(), json);
+		assert_eq!(serde_json::from_value::<NamedToken>(json).unwrap(), orders());
+	}
+
+	#[test]
+	fn named_token_round_trip() {
+		let token = NamedToken::new(
+			NamedParamType::new("values", ParamType::FixedArray(Box::new(ParamType::FixedBytes(2)), 2)),
+			Token::FixedArray(vec![Token::FixedBytes(vec![0, 1]), Token::FixedBytes(vec![2, 3])]),
+		)
+		.unwrap();
+		let json = serde_json::to_string(&token).unwrap();
+		assert_eq!(json, r#"{"name":"values","type":"bytes2[2]","value":["0x0001","0x0203"]}"#);
+		assert_eq!(serde_json::from_str::<NamedToken>(&json).unwrap(), token);
+		assert_eq!(serde_json::from_str::<NamedToken>(&serde_json::to_string(&orders()).unwrap()).unwrap(), orders());
+	}
+
+	#[test]
+	fn unnamed_token_round_trip() {
+		let tokens = [
+			(
+				ParamType::Address,
+				Token::Address([0x11u8; 20].into()),
+				r#""0x1111111111111111111111111111111111111111""#,
+			),
+			(ParamType::FixedBytes(3), Token::FixedBytes(vec![1, 2, 3]), r#""0x010203""#),
+			(ParamType::Bytes, Token::Bytes(vec![]), r#""0x""#),
+			(ParamType::Int(8), Token::Int(!Uint::from(127)), r#""-128""#),
+			(ParamType::Uint(256), Token::Uint(123.into()), r#""123""#),
+			(ParamType::Bool, Token::Bool(false), "false"),
+			(ParamType::String, Token::String("abc".into()), r#""abc""#),
+			(
+				ParamType::FixedArray(Box::new(ParamType::Uint(8)), 2),
+				Token::FixedArray(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+				r#"["1","2"]"#,
+			),
+			(ParamType::Array(Box::new(ParamType::Bool)), Token::Array(vec![]), "[]"),
+		];
+		for (kind, value, json) in tokens {
+			let token = NamedToken::unnamed(kind.clone(), value).unwrap();
+			let expected = format!(r#"{{"name":"","type":"{kind}","value":{json}}}"#);
+			assert_eq!(serde_json::to_string(&token).unwrap(), expected);
+			assert_eq!(serde_json::from_str::<NamedToken>(&expected).unwrap(), token);
+			let without_name = format!(r#"{{"type":"{kind}","value":{json}}}"#);
+			assert_eq!(serde_json::from_str::<NamedToken>(&without_name).unwrap(), token);
+		}
+
+		let token = NamedToken::unnamed(
+			ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Tuple(vec![ParamType::Bool])]),
+			Token::Tuple(vec![Token::Uint(1.into()), Token::Tuple(vec![Token::Bool(true)])]),
+		)
+		.unwrap();
+		let json = r#"{"name":"","type":"tuple","components":[{"name":"","type":"uint256"},{"name":"","type":"tuple","components":[{"name":"","type":"bool"}]}],"value":["1",[true]]}"#;
+		assert_eq!(serde_json::to_string(&token).unwrap(), json);
+		assert_eq!(serde_json::from_str::<NamedToken>(json).unwrap(), token);
+	}
+
+	#[test]
+	fn named_token_invalid_value() {
+		assert!(NamedToken::new(NamedParamType::new("a", ParamType::Bool), Token::Uint(Uint::zero())).is_err());
+		assert!(serde_json::from_str::<NamedToken>(r#"{"name":"a","type":"bytes2[2]","value":["0x0001"]}"#).is_err());
+		assert!(serde_json::from_str::<NamedToken>(r#"{"name":"a","type":"address","value":"0x1234"}"#).is_err());
+		assert!(serde_json::from_str::<NamedToken>(r#"{"name":"a","type":"tuple","value":[]}"#).is_err());
+		assert!(serde_json::from_str::<NamedToken>(r#"{"name":"a","type":"int8","value":"-0"}"#).is_ok());
+		let max = "57896044618658097711785492504343953926634992332820282019728792003956564819968";
+		let json = format!(r#"{{"name":"a","type":"int256","value":"{max}"}}"#);
+		assert!(serde_json::from_str::<NamedToken>(&json).is_err());
+		let json = format!(r#"{{"name":"a","type":"int256","value":"-{max}"}}"#);
+		assert!(serde_json::from_str::<NamedToken>(&json).is_ok());
+	}
+}
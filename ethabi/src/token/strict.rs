@@ -42,16 +42,24 @@ impl Tokenizer for StrictTokenizer {
 		hex::decode(value).map_err(Into::into)
 	}
 
+	// Shorter values are right padded with zeros, the same way they are encoded.
 	fn tokenize_fixed_bytes(value: &str, len: usize) -> Result<Vec<u8>, Error> {
-		let hex: Vec<u8> = hex::decode(value)?;
-		match hex.len() == len {
-			true => Ok(hex),
-			false => Err(Error::InvalidData),
+		let mut hex: Vec<u8> = hex::decode(value)?;
+		if hex.len() > len {
+			return Err(Error::InvalidData);
 		}
+		hex.resize(len, 0);
+		Ok(hex)
 	}
 
+	// Values are either the 64 hex digits of the word or a `0x` prefixed hex
+	// quantity of any length, as shown by block explorers.
 	fn tokenize_uint(value: &str) -> Result<[u8; 32], Error> {
-		let hex: Vec<u8> = hex::decode(value)?;
+		let hex: Vec<u8> = match value.strip_prefix("0x") {
+			Some(quantity) if quantity.is_empty() || quantity.len() > 64 => return Err(Error::InvalidData),
+			Some(quantity) => hex::decode(format!("{quantity:0>64}"))?,
+			None => hex::decode(value)?,
+		};
 		match hex.len() == 32 {
 			true => {
 				let mut uint = [0u8; 32];
@@ -71,7 +79,7 @@ impl Tokenizer for StrictTokenizer {
 mod tests {
 	use crate::{
 		token::{StrictTokenizer, Token, Tokenizer},
-		ParamType,
+		Error, ParamType,
 	};
 
 	#[test]
@@ -122,6 +130,16 @@ mod tests {
 			StrictTokenizer::tokenize(&ParamType::FixedBytes(2), "0017").unwrap(),
 			Token::FixedBytes(vec![0x00, 0x17])
 		);
+		assert_eq!(
+			StrictTokenizer::tokenize(&ParamType::FixedBytes(4), "1234").unwrap(),
+			Token::FixedBytes(vec![0x12, 0x34, 0x00, 0x00])
+		);
+		assert_eq!(StrictTokenizer::tokenize(&ParamType::FixedBytes(1), "").unwrap(), Token::FixedBytes(vec![0x00]));
+		assert!(matches!(
+			StrictTokenizer::tokenize(&ParamType::FixedBytes(16), "1111111111111111111111111111111111111111"),
+			Err(Error::InvalidData)
+		));
+		assert!(StrictTokenizer::tokenize(&ParamType::FixedBytes(2), "123").is_err());
 	}
 
 	#[test]
@@ -145,6 +163,20 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn tokenize_hex_quantity() {
+		assert_eq!(StrictTokenizer::tokenize(&ParamType::Uint(256), "0x1f").unwrap(), Token::Uint(31.into()));
+		assert_eq!(StrictTokenizer::tokenize(&ParamType::Uint(8), "0xF").unwrap(), Token::Uint(15.into()));
+		assert_eq!(StrictTokenizer::tokenize(&ParamType::Int(256), "0x1f").unwrap(), Token::Int(31.into()));
+		assert_eq!(
+			StrictTokenizer::tokenize(&ParamType::Uint(256), &format!("0x{}", "11".repeat(32))).unwrap(),
+			Token::Uint([0x11u8; 32].into())
+		);
+		assert!(StrictTokenizer::tokenize(&ParamType::Uint(256), "0x").is_err());
+		assert!(StrictTokenizer::tokenize(&ParamType::Uint(256), &format!("0x1{}", "11".repeat(32))).is_err());
+		assert!(StrictTokenizer::tokenize(&ParamType::Uint(256), "1f").is_err());
+	}
+
 	#[test]
 	fn tokenize_int() {
 		assert_eq!(
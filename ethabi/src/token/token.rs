@@ -8,18 +8,26 @@
 
 //! Ethereum ABI params.
 
-use core::fmt;
+use core::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	mem,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Address, Bytes, FixedBytes, Int, ParamType, Uint};
+use crate::{no_std_prelude::Cow, Address, Bytes, Error, FixedBytes, Int, ParamType, Result, Uint};
 
 /// Ethereum ABI params.
+///
+/// Tokens implement [`Hash`](core::hash::Hash) so they can be used as set or map keys.
+/// `Int` and `Uint` tokens are hashed on their 32 byte big-endian representation.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Token {
 	/// Address.
 	///
@@ -71,29 +79,118 @@ pub enum Token {
 	Tuple(Vec<Token>),
 }
 
+/// Formats the token in its canonical textual form: `0x` prefixed hex for
+/// addresses and bytes, decimal for integers, `[a,b]` for arrays and `(a,b)`
+/// for tuples. The output can be parsed back with [`Token::from_str_typed`].
 impl fmt::Display for Token {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		match *self {
+		self.display(false).fmt(f)
+	}
+}
+
+/// Formats a token like its [`Display`](fmt::Display) implementation,
+/// optionally with EIP-55 checksummed addresses. Created by [`Token::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayToken<'a> {
+	token: &'a Token,
+	checksum: bool,
+}
+
+impl fmt::Display for DisplayToken<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let list = |f: &mut fmt::Formatter, tokens: &[Token]| {
+			for (i, token) in tokens.iter().enumerate() {
+				if i != 0 {
+					f.write_str(",")?;
+				}
+				token.display(self.checksum).fmt(f)?;
+			}
+			Ok(())
+		};
+
+		match *self.token {
 			Token::Bool(b) => write!(f, "{b}"),
 			Token::String(ref s) => write!(f, "{s}"),
-			Token::Address(ref a) => write!(f, "{a:x}"),
-			Token::Bytes(ref bytes) | Token::FixedBytes(ref bytes) => write!(f, "{}", hex::encode(bytes)),
-			Token::Uint(ref i) | Token::Int(ref i) => write!(f, "{i:x}"),
+			Token::Address(ref a) if self.checksum => f.write_str(&crate::checksum_address(a)),
+			Token::Address(ref a) => write!(f, "{a:#x}"),
+			Token::Bytes(ref bytes) | Token::FixedBytes(ref bytes) => write!(f, "0x{}", hex::encode(bytes)),
+			Token::Uint(ref i) => write!(f, "{i}"),
+			Token::Int(ref i) if i.bit(255) => write!(f, "-{}", !*i + 1),
+			Token::Int(ref i) => write!(f, "{i}"),
 			Token::Array(ref arr) | Token::FixedArray(ref arr) => {
-				let s = arr.iter().map(|ref t| format!("{t}")).collect::<Vec<String>>().join(",");
-
-				write!(f, "[{s}]")
+				f.write_str("[")?;
+				list(f, arr)?;
+				f.write_str("]")
 			}
 			Token::Tuple(ref s) => {
-				let s = s.iter().map(|ref t| format!("{t}")).collect::<Vec<String>>().join(",");
-
-				write!(f, "({s})")
+				f.write_str("(")?;
+				list(f, s)?;
+				f.write_str(")")
 			}
 		}
 	}
 }
 
+impl Hash for Token {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		mem::discriminant(self).hash(state);
+		match self {
+			Token::Address(address) => address.hash(state),
+			Token::FixedBytes(bytes) | Token::Bytes(bytes) => bytes.hash(state),
+			Token::Int(int) | Token::Uint(int) => <[u8; 32]>::from(*int).hash(state),
+			Token::Bool(b) => b.hash(state),
+			Token::String(s) => s.hash(state),
+			Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => tokens.hash(state),
+		}
+	}
+}
+
 impl Token {
+	/// Parses a token of the given type from its textual form, as produced by
+	/// the [`Display`](fmt::Display) implementation.
+	///
+	/// This uses [`LenientTokenizer`](crate::token::LenientTokenizer), so
+	/// integers may also be given in decimal with an optional unit, e.g. `1.5 ether`.
+	#[cfg(feature = "full-serde")]
+	pub fn from_str_typed(ty: &ParamType, s: &str) -> Result<Token> {
+		use crate::token::{LenientTokenizer, Tokenizer};
+
+		LenientTokenizer::tokenize(ty, s)
+	}
+
+	/// Returns a formatter for the token, which writes addresses in their EIP-55
+	/// checksummed form if `checksum` is set and otherwise formats the token like
+	/// its [`Display`](fmt::Display) implementation.
+	///
+	/// ```
+	/// use ethabi::{Address, Token};
+	///
+	/// let address: Address = "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+	/// let token = Token::Array(vec![Token::Address(address)]);
+	/// assert_eq!(token.display(false).to_string(), "[0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed]");
+	/// assert_eq!(token.display(true).to_string(), "[0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed]");
+	/// ```
+	pub fn display(&self, checksum: bool) -> DisplayToken<'_> {
+		DisplayToken { token: self, checksum }
+	}
+
+	/// Creates an address token from its 20 bytes.
+	pub fn address_from_slice(bytes: &[u8]) -> Result<Token> {
+		if bytes.len() != 20 {
+			return Err(Error::Other(Cow::Owned(format!("address must be 20 bytes long, got {}", bytes.len()))));
+		}
+		Ok(Token::Address(Address::from_slice(bytes)))
+	}
+
+	/// Creates an address token from its hex representation, with or without
+	/// `0x` prefix. Letters may be in any case, so checksummed addresses are
+	/// accepted as well.
+	pub fn address_from_str(s: &str) -> Result<Token> {
+		let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))
+			.map_err(|err| Error::Other(Cow::Owned(format!("invalid address `{s}`: {err}"))))?;
+		Token::address_from_slice(&bytes)
+	}
+
 	/// Check whether the type of the token matches the given parameter type.
 	///
 	/// Numeric types (`Int` and `Uint`) type check if the size of the token
@@ -133,7 +230,7 @@ impl Token {
 			}
 			Token::Tuple(ref tokens) => {
 				if let ParamType::Tuple(ref param_type) = *param_type {
-					tokens.iter().enumerate().all(|(i, t)| t.type_check(&param_type[i]))
+					Token::types_check(tokens, param_type)
 				} else {
 					false
 				}
@@ -221,6 +318,146 @@ impl Token {
 		}
 	}
 
+	/// Creates an unsigned integer token from its big-endian byte representation.
+	///
+	/// Fails if `bytes` is longer than 32 bytes.
+	pub fn uint_from_bytes_be(bytes: &[u8]) -> Result<Token> {
+		if bytes.len() > 32 {
+			return Err(Error::InvalidData);
+		}
+		Ok(Token::Uint(Uint::from_big_endian(bytes)))
+	}
+
+	/// Returns the 32 byte big-endian representation of an unsigned integer token.
+	pub fn uint_to_bytes_be(&self) -> Option<[u8; 32]> {
+		match *self {
+			Token::Uint(uint) => Some(uint.into()),
+			_ => None,
+		}
+	}
+
+	/// Sorts the elements of an array token with the given comparator.
+	///
+	/// Decoded arrays keep the order in which their elements were encoded, so
+	/// comparing them with `==` is order sensitive. Sorting both sides with the
+	/// same comparator first gives a deterministic, order independent comparison.
+	/// Tokens other than `Array` and `FixedArray` are returned unchanged.
+	pub fn sorted_by<F>(self, compare: F) -> Token
+	where
+		F: FnMut(&Token, &Token) -> Ordering,
+	{
+		match self {
+			Token::Array(mut tokens) => {
+				tokens.sort_by(compare);
+				Token::Array(tokens)
+			}
+			Token::FixedArray(mut tokens) => {
+				tokens.sort_by(compare);
+				Token::FixedArray(tokens)
+			}
+			token => token,
+		}
+	}
+
+	/// Creates a tuple token from anything convertible into tokens.
+	///
+	/// ```
+	/// use ethabi::{Address, Token, Uint};
+	///
+	/// let token = Token::tuple([Token::from(Address::zero()), Token::from(Uint::from(1))]);
+	/// assert_eq!(token, Token::Tuple(vec![Token::Address(Address::zero()), Token::Uint(1.into())]));
+	/// ```
+	pub fn tuple<I, T>(tokens: I) -> Token
+	where
+		I: IntoIterator<Item = T>,
+		T: Into<Token>,
+	{
+		Token::Tuple(tokens.into_iter().map(Into::into).collect())
+	}
+
+	/// Formats the token as an indented tree for reading in logs, indenting
+	/// each nesting level by `indent` spaces.
+	///
+	/// Every value is preceded by its kind, arrays are enclosed in brackets,
+	/// tuples in parentheses, and addresses and bytes are shown in hex.
+	///
+	/// ```
+	/// use ethabi::{Address, Token};
+	///
+	/// let token = Token::Tuple(vec![
+	///     Token::Address(Address::repeat_byte(0x11)),
+	///     Token::Array(vec![Token::Uint(1.into()), Token::Bool(true)]),
+	/// ]);
+	/// assert_eq!(
+	///     token.pretty(2),
+	///     "tuple (
+	///   address 0x1111111111111111111111111111111111111111,
+	///   array [
+	///     uint 1,
+	///     bool true
+	///   ]
+	/// )"
+	/// );
+	/// ```
+	pub fn pretty(&self, indent: usize) -> String {
+		let mut out = String::new();
+		self.write_pretty(&mut out, indent, 0);
+		out
+	}
+
+	fn write_pretty(&self, out: &mut String, indent: usize, level: usize) {
+		let (kind, open, close, tokens) = match self {
+			Token::Array(tokens) => ("array", '[', ']', tokens),
+			Token::FixedArray(tokens) => ("fixed_array", '[', ']', tokens),
+			Token::Tuple(tokens) => ("tuple", '(', ')', tokens),
+			Token::Address(_) => return out.push_str(&format!("address {self}")),
+			Token::FixedBytes(bytes) => return out.push_str(&format!("bytes{} {self}", bytes.len())),
+			Token::Bytes(_) => return out.push_str(&format!("bytes {self}")),
+			Token::Int(_) => return out.push_str(&format!("int {self}")),
+			Token::Uint(_) => return out.push_str(&format!("uint {self}")),
+			Token::Bool(_) => return out.push_str(&format!("bool {self}")),
+			Token::String(s) => return out.push_str(&format!("string {s:?}")),
+		};
+
+		out.push_str(kind);
+		out.push(' ');
+		out.push(open);
+		for (i, token) in tokens.iter().enumerate() {
+			out.push_str(if i == 0 { "\n" } else { ",\n" });
+			out.push_str(&" ".repeat(indent * (level + 1)));
+			token.write_pretty(out, indent, level + 1);
+		}
+		if !tokens.is_empty() {
+			out.push('\n');
+			out.push_str(&" ".repeat(indent * level));
+		}
+		out.push(close);
+	}
+
+	/// Creates an array token, checking that every element matches `element_type`.
+	///
+	/// ```
+	/// use ethabi::{ParamType, Token};
+	///
+	/// let array = Token::array(&ParamType::Bool, vec![Token::Bool(true), Token::Bool(false)]).unwrap();
+	/// assert_eq!(array, Token::Array(vec![Token::Bool(true), Token::Bool(false)]));
+	/// assert!(Token::array(&ParamType::Bool, vec![Token::Bool(true), Token::Uint(1.into())]).is_err());
+	/// ```
+	pub fn array(element_type: &ParamType, elements: Vec<Token>) -> Result<Token> {
+		check_elements(element_type, &elements)?;
+		Ok(Token::Array(elements))
+	}
+
+	/// Creates a fixed array token, checking that it has `len` elements and that
+	/// every element matches `element_type`.
+	pub fn fixed_array(element_type: &ParamType, len: usize, elements: Vec<Token>) -> Result<Token> {
+		if elements.len() != len {
+			return Err(Error::Other(Cow::Owned(format!("expected {len} array elements, got {}", elements.len()))));
+		}
+		check_elements(element_type, &elements)?;
+		Ok(Token::FixedArray(elements))
+	}
+
 	/// Check if all the types of the tokens match the given parameter types.
 	pub fn types_check(tokens: &[Token], param_types: &[ParamType]) -> bool {
 		param_types.len() == tokens.len() && {
@@ -229,6 +466,11 @@ impl Token {
 	}
 
 	/// Check if the token is a dynamic type resulting in prefixed encoding
+	///
+	/// Dynamic tokens are encoded in the tail and referenced by an offset in the head.
+	/// This agrees with [`ParamType::is_dynamic`]: `bytes`, `string` and dynamic arrays
+	/// are dynamic, and so are fixed arrays and tuples with a dynamic element. As the
+	/// element type of an empty fixed array is unknown, empty fixed arrays are static.
 	pub fn is_dynamic(&self) -> bool {
 		match self {
 			Token::Bytes(_) | Token::String(_) | Token::Array(_) => true,
@@ -239,11 +481,79 @@ impl Token {
 	}
 }
 
+fn check_elements(element_type: &ParamType, elements: &[Token]) -> Result<()> {
+	match elements.iter().position(|element| !element.type_check(element_type)) {
+		Some(index) => Err(Error::Other(Cow::Owned(format!(
+			"array element {index} `{}` does not match element type `{element_type}`",
+			elements[index]
+		)))),
+		None => Ok(()),
+	}
+}
+
+impl From<Address> for Token {
+	fn from(address: Address) -> Self {
+		Token::Address(address)
+	}
+}
+
+impl From<Uint> for Token {
+	fn from(uint: Uint) -> Self {
+		Token::Uint(uint)
+	}
+}
+
+impl From<bool> for Token {
+	fn from(b: bool) -> Self {
+		Token::Bool(b)
+	}
+}
+
+impl From<String> for Token {
+	fn from(s: String) -> Self {
+		Token::String(s)
+	}
+}
+
+impl From<&str> for Token {
+	fn from(s: &str) -> Self {
+		Token::String(s.to_owned())
+	}
+}
+
+impl From<Bytes> for Token {
+	fn from(bytes: Bytes) -> Self {
+		Token::Bytes(bytes)
+	}
+}
+
+/// Creates a [`Token::Tuple`] from a list of values convertible into tokens.
+///
+/// ```
+/// use ethabi::{tuple, Address, Token, Uint};
+///
+/// let token = tuple![Address::zero(), Uint::from(1), tuple![true, "foo"]];
+/// assert_eq!(
+///     token,
+///     Token::Tuple(vec![
+///         Token::Address(Address::zero()),
+///         Token::Uint(1.into()),
+///         Token::Tuple(vec![Token::Bool(true), Token::String("foo".into())]),
+///     ])
+/// );
+/// ```
+#[macro_export]
+macro_rules! tuple {
+	($($token:expr),* $(,)?) => {
+		$crate::Token::tuple::<_, $crate::Token>([$($crate::Token::from($token)),*])
+	};
+}
+
 #[cfg(test)]
 mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
-	use crate::{ParamType, Token};
+	use crate::{Address, Error, ParamType, Token, Uint};
 
 	#[test]
 	fn test_type_check() {
@@ -298,6 +608,51 @@ mod tests {
 			vec![Token::FixedArray(vec![Token::Bool(false), Token::Bool(true)])],
 			vec![ParamType::FixedArray(Box::new(ParamType::Address), 2)],
 		);
+
+		assert_type_check(
+			vec![Token::Tuple(vec![Token::Bool(false), Token::Uint(0.into())])],
+			vec![ParamType::Tuple(vec![ParamType::Bool, ParamType::Uint(256)])],
+		);
+		assert_not_type_check(
+			vec![Token::Tuple(vec![Token::Bool(false)])],
+			vec![ParamType::Tuple(vec![ParamType::Bool, ParamType::Uint(256)])],
+		);
+		assert_not_type_check(
+			vec![Token::Tuple(vec![Token::Bool(false), Token::Uint(0.into()), Token::Bool(true)])],
+			vec![ParamType::Tuple(vec![ParamType::Bool, ParamType::Uint(256)])],
+		);
+	}
+
+	#[test]
+	fn test_uint_bytes_be() {
+		let mut bytes = [0u8; 32];
+		bytes[30] = 0x01;
+		bytes[31] = 0x02;
+
+		assert_eq!(Token::uint_from_bytes_be(&[0x01, 0x02]).unwrap(), Token::Uint(0x0102.into()));
+		assert_eq!(Token::uint_from_bytes_be(&bytes).unwrap(), Token::Uint(0x0102.into()));
+		assert_eq!(Token::uint_from_bytes_be(&[]).unwrap(), Token::Uint(0.into()));
+		assert!(Token::uint_from_bytes_be(&[0u8; 33]).is_err());
+
+		assert_eq!(Token::Uint(0x0102.into()).uint_to_bytes_be(), Some(bytes));
+		assert_eq!(Token::Bool(true).uint_to_bytes_be(), None);
+	}
+
+	#[test]
+	fn test_sorted_by() {
+		fn tuple(id: u64, flag: bool) -> Token {
+			Token::Tuple(vec![Token::Uint(id.into()), Token::Bool(flag)])
+		}
+
+		fn first_uint(token: &Token) -> Uint {
+			token.clone().into_tuple().and_then(|t| t[0].clone().into_uint()).unwrap()
+		}
+
+		let decoded = Token::Array(vec![tuple(3, true), tuple(1, false), tuple(2, true)]);
+		let expected = Token::Array(vec![tuple(1, false), tuple(2, true), tuple(3, true)]);
+
+		assert_eq!(decoded.sorted_by(|a, b| first_uint(a).cmp(&first_uint(b))), expected);
+		assert_eq!(Token::Bool(true).sorted_by(|_, _| core::cmp::Ordering::Equal), Token::Bool(true));
 	}
 
 	#[test]
@@ -314,4 +669,171 @@ mod tests {
 		assert!(Token::FixedArray(vec![Token::String("".into())]).is_dynamic());
 		assert!(Token::FixedArray(vec![Token::Array(vec![Token::Bool(false)])]).is_dynamic());
 	}
+
+	#[test]
+	fn test_is_dynamic_matches_param_type() {
+		let cases = vec![
+			(Token::Bool(true), ParamType::Bool),
+			(Token::Bytes(vec![]), ParamType::Bytes),
+			(Token::Array(vec![]), ParamType::Array(Box::new(ParamType::Uint(256)))),
+			(Token::FixedArray(vec![Token::Bool(true)]), ParamType::FixedArray(Box::new(ParamType::Bool), 1)),
+			(Token::FixedArray(vec![Token::Bytes(vec![])]), ParamType::FixedArray(Box::new(ParamType::Bytes), 1)),
+			(
+				Token::Tuple(vec![Token::Bool(true), Token::Uint(1.into())]),
+				ParamType::Tuple(vec![ParamType::Bool, ParamType::Uint(256)]),
+			),
+			(
+				Token::Tuple(vec![Token::Bool(true), Token::FixedArray(vec![Token::String("".into())])]),
+				ParamType::Tuple(vec![ParamType::Bool, ParamType::FixedArray(Box::new(ParamType::String), 1)]),
+			),
+		];
+		for (token, param_type) in cases {
+			assert!(token.type_check(&param_type));
+			assert_eq!(token.is_dynamic(), param_type.is_dynamic(), "{param_type}");
+		}
+	}
+
+	#[test]
+	fn test_display() {
+		let token = Token::Tuple(vec![
+			Token::Address([0x11u8; 20].into()),
+			Token::Array(vec![Token::Uint(1.into()), Token::Uint(256.into())]),
+			Token::Int(!Uint::zero()),
+			Token::Int(5.into()),
+			Token::Bytes(vec![0x12, 0x34]),
+			Token::FixedArray(vec![Token::Bool(true), Token::Bool(false)]),
+			Token::String("gavofyork".into()),
+		]);
+		assert_eq!(
+			token.to_string(),
+			"(0x1111111111111111111111111111111111111111,[1,256],-1,5,0x1234,[true,false],gavofyork)"
+		);
+	}
+
+	#[test]
+	fn test_pretty() {
+		let token = Token::Tuple(vec![
+			Token::Int(!Uint::from(5) + 1),
+			Token::FixedBytes(vec![0x12, 0x34]),
+			Token::Array(vec![]),
+			Token::FixedArray(vec![Token::Tuple(vec![Token::String("a\"b".into()), Token::Bytes(vec![0xff])])]),
+		]);
+		let expected = r#"tuple (
+    int -5,
+    bytes2 0x1234,
+    array [],
+    fixed_array [
+        tuple (
+            string "a\"b",
+            bytes 0xff
+        )
+    ]
+)"#;
+		assert_eq!(token.pretty(4), expected);
+		assert_eq!(Token::Tuple(vec![Token::Bool(false)]).pretty(0), "tuple (\nbool false\n)");
+		assert_eq!(Token::Uint(7.into()).pretty(2), "uint 7");
+	}
+
+	#[test]
+	fn test_array_constructors() {
+		let pair = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+		let element = Token::Tuple(vec![Token::Address(Address::zero()), Token::Uint(1.into())]);
+
+		assert_eq!(Token::array(&pair, vec![element.clone()]).unwrap(), Token::Array(vec![element.clone()]));
+		assert_eq!(Token::array(&pair, vec![]).unwrap(), Token::Array(vec![]));
+		assert_eq!(
+			Token::fixed_array(&pair, 2, vec![element.clone(), element.clone()]).unwrap(),
+			Token::FixedArray(vec![element.clone(), element.clone()])
+		);
+
+		let mismatch = vec![element.clone(), Token::Tuple(vec![Token::Bool(true), Token::Uint(1.into())])];
+		match Token::array(&pair, mismatch.clone()) {
+			Err(Error::Other(msg)) => {
+				assert_eq!(msg, "array element 1 `(true,1)` does not match element type `(address,uint256)`")
+			}
+			other => panic!("unexpected result {other:?}"),
+		}
+		assert!(matches!(Token::fixed_array(&pair, 2, mismatch), Err(Error::Other(_))));
+		match Token::fixed_array(&pair, 2, vec![element]) {
+			Err(Error::Other(msg)) => assert_eq!(msg, "expected 2 array elements, got 1"),
+			other => panic!("unexpected result {other:?}"),
+		}
+	}
+
+	#[cfg(feature = "full-serde")]
+	#[test]
+	fn test_from_str_typed() {
+		let ty = ParamType::Tuple(vec![
+			ParamType::Address,
+			ParamType::Array(Box::new(ParamType::Uint(256))),
+			ParamType::Int(256),
+			ParamType::FixedBytes(2),
+			ParamType::String,
+		]);
+		let token = Token::Tuple(vec![
+			Token::Address([0x11u8; 20].into()),
+			Token::Array(vec![Token::Uint(1.into()), Token::Uint(256.into())]),
+			Token::Int(!Uint::zero()),
+			Token::FixedBytes(vec![0x12, 0x34]),
+			Token::String("gavofyork".into()),
+		]);
+		assert_eq!(Token::from_str_typed(&ty, &token.to_string()).unwrap(), token);
+		assert_eq!(Token::from_str_typed(&ParamType::Uint(256), "1 gwei").unwrap(), Token::Uint(1_000_000_000.into()));
+		assert!(Token::from_str_typed(&ParamType::Bool, "maybe").is_err());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_hash() {
+		use std::collections::HashSet;
+
+		let tokens = [
+			Token::Uint(1.into()),
+			Token::Int(1.into()),
+			Token::Bytes(vec![1]),
+			Token::FixedBytes(vec![1]),
+			Token::Array(vec![Token::Bool(true)]),
+			Token::FixedArray(vec![Token::Bool(true)]),
+			Token::Tuple(vec![Token::Bool(true), Token::String("foo".into())]),
+		];
+		let set: HashSet<_> = tokens.iter().cloned().chain(tokens.iter().cloned()).collect();
+
+		assert_eq!(set.len(), tokens.len());
+		assert!(tokens.iter().all(|token| set.contains(token)));
+		assert!(!set.contains(&Token::Uint(2.into())));
+	}
+
+	#[test]
+	fn test_address_constructors() {
+		let address = Token::Address([0x5a; 20].into());
+		assert_eq!(Token::address_from_slice(&[0x5a; 20]).unwrap(), address);
+		assert_eq!(Token::address_from_str("0x5a5A5a5A5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a").unwrap(), address);
+		assert_eq!(Token::address_from_str("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a").unwrap(), address);
+
+		assert!(matches!(Token::address_from_slice(&[0; 19]), Err(Error::Other(_))));
+		assert!(matches!(Token::address_from_str("0x5a5a"), Err(Error::Other(_))));
+		assert!(matches!(Token::address_from_str("0xzz5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a"), Err(Error::Other(_))));
+	}
+
+	#[test]
+	fn test_tuple() {
+		let expected = Token::Tuple(vec![
+			Token::Address([0x11u8; 20].into()),
+			Token::Tuple(vec![Token::Uint(7.into()), Token::Bytes(vec![1, 2])]),
+			Token::String("foo".into()),
+		]);
+		assert_eq!(
+			Token::tuple([
+				Token::from(Address::from([0x11u8; 20])),
+				Token::tuple([Token::from(Uint::from(7)), Token::from(vec![1u8, 2])]),
+				Token::from("foo"),
+			]),
+			expected
+		);
+		assert_eq!(
+			crate::tuple![Address::from([0x11u8; 20]), crate::tuple![Uint::from(7), vec![1u8, 2]], "foo"],
+			expected
+		);
+		assert_eq!(crate::tuple![], Token::Tuple(vec![]));
+	}
 }
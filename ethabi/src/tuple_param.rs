@@ -10,11 +10,12 @@
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{param_type::Writer, ParamType};
+use crate::ParamType;
+#[cfg(feature = "serde")]
 use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{
 	de::{Error, MapAccess, Visitor},
-	ser::SerializeMap,
 	Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -29,8 +30,13 @@ pub struct TupleParam {
 
 	/// Additional Internal type.
 	pub internal_type: Option<String>,
+
+	/// Names and internal types of the components of a tuple, or of a tuple
+	/// array element. Empty if they are unknown or if the param is not a tuple.
+	pub components: Vec<TupleParam>,
 }
 
+#[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for TupleParam {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -40,8 +46,10 @@ impl<'a> Deserialize<'a> for TupleParam {
 	}
 }
 
+#[cfg(feature = "serde")]
 struct TupleParamVisitor;
 
+#[cfg(feature = "serde")]
 impl<'a> Visitor<'a> for TupleParamVisitor {
 	type Value = TupleParam;
 
@@ -90,38 +98,27 @@ impl<'a> Visitor<'a> for TupleParamVisitor {
 		}
 
 		let mut kind = kind.ok_or_else(|| Error::missing_field("kind"))?;
-		crate::param::set_tuple_components(&mut kind, components)?;
-		Ok(TupleParam { name, kind, internal_type })
+		let components = crate::param::set_tuple_components(&mut kind, components)?;
+		Ok(TupleParam { name, kind, internal_type, components })
 	}
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for TupleParam {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
-		let mut map = serializer.serialize_map(None)?;
-		if let Some(ref internal_type) = self.internal_type {
-			map.serialize_entry("internalType", internal_type)?;
-		}
-		if let Some(name) = &self.name {
-			map.serialize_entry("name", name)?;
-		}
-		map.serialize_entry("type", &Writer::write_for_abi(&self.kind, false))?;
-		if let Some(inner_tuple) = crate::param::inner_tuple(&self.kind) {
-			map.serialize_key("components")?;
-			map.serialize_value(&crate::param::SerializeableParamVec(inner_tuple))?;
-		}
-		map.end()
+		crate::param::SerializeableParam(&self.kind, Some(self)).serialize(serializer)
 	}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde"))]
 mod tests {
 	#[cfg(not(feature = "std"))]
 	use crate::no_std_prelude::*;
 	use crate::{
-		tests::{assert_json_eq, assert_ser_de},
+		tests::{assert_json_eq, assert_ser_de, component},
 		ParamType, TupleParam,
 	};
 
@@ -136,7 +133,12 @@ mod tests {
 
 		assert_eq!(
 			deserialized,
-			TupleParam { name: Some("foo".to_owned()), kind: ParamType::Address, internal_type: None }
+			TupleParam {
+				name: Some("foo".to_owned()),
+				kind: ParamType::Address,
+				internal_type: None,
+				components: vec![]
+			}
 		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
@@ -157,7 +159,8 @@ mod tests {
 			TupleParam {
 				name: Some("foo".to_owned()),
 				kind: ParamType::Address,
-				internal_type: Some("struct Verifier.Proof".to_string())
+				internal_type: Some("struct Verifier.Proof".to_string()),
+				components: vec![]
 			}
 		);
 
@@ -172,7 +175,10 @@ mod tests {
 
 		let deserialized: TupleParam = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, TupleParam { name: None, kind: ParamType::Address, internal_type: None });
+		assert_eq!(
+			deserialized,
+			TupleParam { name: None, kind: ParamType::Address, internal_type: None, components: vec![] }
+		);
 
 		assert_json_eq(s, serde_json::to_string(&deserialized).unwrap().as_str());
 	}
@@ -203,7 +209,15 @@ mod tests {
 			TupleParam {
 				name: None,
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(
+						None,
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -239,7 +253,15 @@ mod tests {
 			TupleParam {
 				name: None,
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(Some("amount"), ParamType::Uint(48), vec![]),
+					component(
+						Some("things"),
+						ParamType::Tuple(vec![ParamType::Address]),
+						vec![component(Some("baseTupleParam"), ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -274,7 +296,12 @@ mod tests {
 					ParamType::Address,
 					ParamType::Address
 				]))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(None, ParamType::Address, vec![]),
+					component(None, ParamType::Address, vec![])
+				]
 			}
 		);
 
@@ -304,7 +331,11 @@ mod tests {
 					ParamType::Uint(8),
 					ParamType::Uint(16),
 				]))))),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(8), vec![]),
+					component(None, ParamType::Uint(16), vec![])
+				]
 			}
 		);
 
@@ -338,7 +369,12 @@ mod tests {
 					Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
 					2
 				),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(None, ParamType::Uint(48), vec![]),
+					component(None, ParamType::Address, vec![]),
+					component(None, ParamType::Address, vec![])
+				]
 			}
 		);
 
@@ -379,7 +415,19 @@ mod tests {
 					ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
 					ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42,)
 				]),
-				internal_type: None
+				internal_type: None,
+				components: vec![
+					component(
+						None,
+						ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Address]))),
+						vec![component(None, ParamType::Address, vec![])]
+					),
+					component(
+						None,
+						ParamType::FixedArray(Box::new(ParamType::Tuple(vec![ParamType::Address])), 42),
+						vec![component(None, ParamType::Address, vec![])]
+					)
+				]
 			}
 		);
 
@@ -8,7 +8,9 @@
 
 //! Utils used by different modules.
 
-use crate::Word;
+#[cfg(all(feature = "serde", not(feature = "std")))]
+use crate::no_std_prelude::*;
+use crate::{errors, no_std_prelude::Cow, Error, Word};
 
 /// Converts a u32 to a right aligned array of 32 bytes.
 pub fn pad_u32(value: u32) -> Word {
@@ -17,33 +19,62 @@ pub fn pad_u32(value: u32) -> Word {
 	padded
 }
 
-// This is a workaround to support non-spec compliant function and event names,
-// see: https://github.com/paritytech/parity/issues/4122
-#[cfg(feature = "serde")]
-pub(crate) mod sanitize_name {
-	#[cfg(not(feature = "std"))]
-	use crate::no_std_prelude::*;
-	use serde::{Deserialize, Deserializer};
+/// Returns the 32 byte word starting at byte `offset` of `data` without copying it.
+pub(crate) fn word_at(data: &[u8], offset: usize) -> Option<&Word> {
+	data.get(offset..offset.checked_add(32)?)?.try_into().ok()
+}
 
-	pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
-	where
-		D: Deserializer<'de>,
-	{
-		let mut name = String::deserialize(deserializer)?;
-		sanitize_name(&mut name);
-		Ok(name)
-	}
+/// Splits call or revert data into its 4 byte selector and the encoded
+/// arguments following it.
+///
+/// `what` names the data in the error returned if it is shorter than 4 bytes.
+///
+/// ```
+/// let (selector, args) = ethabi::split_selector(&[0xa9, 0x05, 0x9c, 0xbb, 0x01], "call data").unwrap();
+/// assert_eq!(selector, [0xa9, 0x05, 0x9c, 0xbb]);
+/// assert_eq!(args, [0x01]);
+/// assert!(ethabi::split_selector(&[0xa9, 0x05], "call data").is_err());
+/// ```
+pub fn split_selector<'a>(data: &'a [u8], what: &str) -> errors::Result<([u8; 4], &'a [u8])> {
+	let selector = data.get(..4).and_then(|selector| selector.try_into().ok()).ok_or_else(|| {
+		Error::Other(Cow::Owned(format!("{what} too short: expected at least 4 bytes, got {}", data.len())))
+	})?;
+	Ok((selector, &data[4..]))
+}
 
-	fn sanitize_name(name: &mut String) {
-		if let Some(i) = name.find('(') {
-			name.truncate(i);
-		}
+/// Strips the parameter list from a function, event or error name, e.g. turns
+/// `transfer(address,uint256)` into `transfer`.
+///
+/// Names in ABI files are sanitized like this when deserializing, as some
+/// tools emit signatures in place of names, see
+/// <https://github.com/paritytech/parity/issues/4122>.
+///
+/// ```
+/// assert_eq!(ethabi::sanitize_name("transfer(address,uint256)"), "transfer");
+/// assert_eq!(ethabi::sanitize_name("transfer"), "transfer");
+/// ```
+pub fn sanitize_name(name: &str) -> &str {
+	match name.find('(') {
+		Some(i) => &name[..i],
+		None => name,
 	}
 }
 
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_sanitized_name<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	let mut name = <String as serde::Deserialize>::deserialize(deserializer)?;
+	name.truncate(sanitize_name(&name).len());
+	Ok(name)
+}
+
 #[cfg(test)]
 mod tests {
-	use super::pad_u32;
+	use super::{pad_u32, sanitize_name, split_selector, word_at};
+	#[cfg(not(feature = "std"))]
+	use crate::no_std_prelude::*;
 	use hex_literal::hex;
 
 	#[test]
@@ -66,4 +97,29 @@ mod tests {
 			hex!("00000000000000000000000000000000000000000000000000000000ffffffff").to_vec()
 		);
 	}
+
+	#[test]
+	fn test_word_at() {
+		let data: Vec<u8> = (0..65).collect();
+		assert_eq!(word_at(&data, 0).unwrap()[..], data[..32]);
+		assert_eq!(word_at(&data, 33).unwrap()[..], data[33..]);
+		assert_eq!(word_at(&data, 34), None);
+		assert_eq!(word_at(&data, usize::MAX), None);
+	}
+
+	#[test]
+	fn test_split_selector() {
+		let data: Vec<u8> = (0..6).collect();
+		assert_eq!(split_selector(&data, "data").unwrap(), ([0, 1, 2, 3], &data[4..]));
+		assert_eq!(split_selector(&data[..4], "data").unwrap(), ([0, 1, 2, 3], &[][..]));
+		assert!(split_selector(&data[..3], "data").is_err());
+	}
+
+	#[test]
+	fn test_sanitize_name() {
+		assert_eq!(sanitize_name("foo(uint256,(bool,address))"), "foo");
+		assert_eq!(sanitize_name("foo()"), "foo");
+		assert_eq!(sanitize_name("foo"), "foo");
+		assert_eq!(sanitize_name(""), "");
+	}
 }
@@ -11,12 +11,41 @@ use_contract!(constructor, "../res/constructor.abi");
 use_contract!(validators, "../res/Validators.abi");
 use_contract!(operations, "../res/Operations.abi");
 use_contract!(urlhint, "../res/urlhint.abi");
+use_contract!(urlhint_h160, "../res/urlhint.abi", bytes20_as_h160 = "true");
 use_contract!(test_rust_keywords, "../res/test_rust_keywords.abi");
+use_contract!(eip20_amount, "../res/eip20.abi", uint_type = "crate::Amount");
+use_contract!(
+	eip20_u128,
+	"../res/eip20.abi",
+	uint_type = "u128",
+	uint_from = "crate::u128_from_uint",
+	uint_into = "ethabi::Uint::from"
+);
+
+/// Token amount, used as the unsigned integer type of `eip20_amount`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Amount(pub ethabi::Uint);
+
+impl From<ethabi::Uint> for Amount {
+	fn from(value: ethabi::Uint) -> Self {
+		Amount(value)
+	}
+}
+
+impl From<Amount> for ethabi::Uint {
+	fn from(amount: Amount) -> Self {
+		amount.0
+	}
+}
+
+fn u128_from_uint(value: ethabi::Uint) -> u128 {
+	value.low_u128()
+}
 
 #[cfg(test)]
 mod tests {
-	use crate::{eip20, validators};
-	use ethabi::{Address, Uint};
+	use crate::{eip20, eip20_amount, eip20_u128, urlhint, urlhint_h160, validators, Amount};
+	use ethabi::{ethereum_types::H160, Address, Uint};
 	use hex_literal::hex;
 
 	struct Wrapper([u8; 20]);
@@ -44,6 +73,43 @@ mod tests {
 		assert_eq!(expected, hex::encode(encoded_from_vec_wrapped));
 	}
 
+	#[test]
+	fn test_bytes20_as_h160() {
+		let commit = [0x11u8; 20];
+
+		let encoded = urlhint::functions::hint::encode_input([0u8; 32], "repo", commit);
+		let encoded_h160 = urlhint_h160::functions::hint::encode_input([0u8; 32], "repo", H160::from(commit));
+		assert_eq!(encoded, encoded_h160);
+
+		let output = ethabi::encode(&[
+			ethabi::Token::String("repo".into()),
+			ethabi::Token::FixedBytes(commit.to_vec()),
+			ethabi::Token::Address([0x22u8; 20].into()),
+		]);
+		let (_, decoded, _) = urlhint::functions::entries::decode_output(&output).unwrap();
+		let (_, decoded_h160, _) = urlhint_h160::functions::entries::decode_output(&output).unwrap();
+		assert_eq!(decoded, commit);
+		assert_eq!(decoded_h160, H160::from(commit));
+	}
+
+	#[test]
+	fn test_uint_type() {
+		let to = Address::from([0x11u8; 20]);
+
+		let encoded = eip20::functions::transfer::encode_input(to, 100);
+		let encoded_amount = eip20_amount::functions::transfer::encode_input(to, Amount(100.into()));
+		assert_eq!(encoded, encoded_amount);
+
+		let output = ethabi::encode(&[ethabi::Token::Uint(1000.into())]);
+		let total = eip20_amount::functions::total_supply::decode_output(&output).unwrap();
+		assert_eq!(total, Amount(1000.into()));
+
+		// `u128` and `ethabi::Uint` can't implement `From` for each other outside of their crates
+		let encoded_u128 = eip20_u128::functions::transfer::encode_input(to, 100u128);
+		assert_eq!(encoded, encoded_u128);
+		assert_eq!(eip20_u128::functions::total_supply::decode_output(&output).unwrap(), 1000u128);
+	}
+
 	#[test]
 	fn test_decoding_function_output() {
 		// Make sure that the output param type of the derived contract is correct